@@ -1,11 +1,12 @@
 #![feature(test)]
 
+#[macro_use]
 extern crate bip_bencode;
 extern crate test;
 
 #[cfg(test)]
 mod benches {
-    use bip_bencode::{BencodeRef, BDecodeOpt};
+    use bip_bencode::{BencodeRef, BDecodeOpt, BRefAccess, BMutAccess, BencodeMut};
     use test::Bencher;
 
     #[bench]
@@ -21,4 +22,48 @@ mod benches {
 
         b.iter(|| BencodeRef::decode(&bencode[..], BDecodeOpt::default()).unwrap());
     }
-}
\ No newline at end of file
+
+    #[bench]
+    fn bench_multi_file_dict_lookup(b: &mut Bencher) {
+        let mut files = BencodeMut::new_list();
+        {
+            let files_access = files.list_mut().unwrap();
+
+            for index in 0..10000 {
+                files_access.push(ben_map!{
+                    "length" => ben_int!(index),
+                    "md5sum" => ben_bytes!("00000000000000000000000000000000"),
+                    "path"   => ben_list!(ben_bytes!(format!("file_{}", index)))
+                });
+            }
+        }
+        let bencode = (ben_map!{ "files" => files }).encode();
+        let decoded = BencodeRef::decode(&bencode[..], BDecodeOpt::default()).unwrap();
+
+        b.iter(|| {
+            let files = decoded.dict().unwrap().lookup(b"files").unwrap().list().unwrap();
+
+            files.into_iter()
+                .map(|file| file.dict().unwrap().lookup(b"length").unwrap().int().unwrap())
+                .sum::<i64>()
+        });
+    }
+
+    #[bench]
+    fn bench_static_key_dict_build(b: &mut Bencher) {
+        b.iter(|| {
+            BencodeMut::dict_from_iter(vec![("announce", ben_int!(0)), ("comment", ben_int!(1)),
+                                            ("created by", ben_int!(2)), ("creation date", ben_int!(3)),
+                                            ("info", ben_int!(4))])
+        });
+    }
+
+    #[bench]
+    fn bench_owned_key_dict_build(b: &mut Bencher) {
+        b.iter(|| {
+            BencodeMut::dict_from_iter(vec![(b"announce".to_vec(), ben_int!(0)), (b"comment".to_vec(), ben_int!(1)),
+                                            (b"created by".to_vec(), ben_int!(2)), (b"creation date".to_vec(), ben_int!(3)),
+                                            (b"info".to_vec(), ben_int!(4))])
+        });
+    }
+}
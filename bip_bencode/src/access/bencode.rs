@@ -1,5 +1,9 @@
 use access::dict::BDictAccess;
 use access::list::BListAccess;
+use error::{BencodeParseError, BencodeParseErrorKind, BencodeParseResult};
+use mutable::bencode_mut::{self, BencodeMut};
+use reference::bencode_ref::BencodeRef;
+use reference::decode_opt::BDecodeOpt;
 
 /// Abstract representation of a `BencodeRef` object.
 pub enum BencodeRefKind<'a, K: 'a, V: 'a> {
@@ -35,6 +39,106 @@ pub trait BRefAccess: Sized {
 
     /// Attempt to access the bencode as an `BDictAccess`.
     fn dict(&self) -> Option<&BDictAccess<Self::BKey, Self::BType>>;
+
+    /// Attempt to access the bencode integer as a `bool`.
+    ///
+    /// Only `1` maps to `true` and only `0` maps to `false`, any other integer
+    /// (or a non integer) yields `None`. This is stricter than some callers, such
+    /// as private flag parsing, which treat any value other than `1` as `false`.
+    fn bool(&self) -> Option<bool> {
+        match self.int() {
+            Some(1) => Some(true),
+            Some(0) => Some(false),
+            _       => None
+        }
+    }
+
+    /// Attempt to access the bencode integer as a `usize`.
+    ///
+    /// Negative integers, and integers too large for a `usize` on the current target (such as
+    /// above `u32::MAX` on 32 bit platforms), yield `None` instead of being truncated.
+    fn int_as_usize(&self) -> Option<usize> {
+        self.int().and_then(|n| {
+            if n < 0 || n as u64 > ::std::usize::MAX as u64 { None } else { Some(n as usize) }
+        })
+    }
+
+    /// Whether or not the bencode is an integer.
+    fn is_int(&self) -> bool {
+        self.int().is_some()
+    }
+
+    /// Whether or not the bencode is a byte string.
+    fn is_bytes(&self) -> bool {
+        self.bytes().is_some()
+    }
+
+    /// Whether or not the bencode is a list.
+    fn is_list(&self) -> bool {
+        self.list().is_some()
+    }
+
+    /// Whether or not the bencode is a dictionary.
+    fn is_dict(&self) -> bool {
+        self.dict().is_some()
+    }
+
+    /// Decode the bencode bytes, which themselves contain bencode, into an owned `BencodeMut`.
+    ///
+    /// Returns an error if the bencode is not bytes, or if the bytes fail to decode.
+    fn decode_nested(&self, opt: BDecodeOpt) -> BencodeParseResult<BencodeMut<'static>> {
+        let bytes = try!(self.bytes().ok_or_else(|| {
+            BencodeParseError::from_kind(BencodeParseErrorKind::InvalidNestedType{ found: self.type_name().to_owned() })
+        }));
+        let nested = try!(BencodeRef::decode(bytes, opt));
+
+        Ok(bencode_mut::to_owned_mut(&nested))
+    }
+
+    /// Every byte string within the bencode, paired with the dictionary keys leading to it.
+    ///
+    /// Dictionary keys are not yielded as leaves, and list elements do not add to the path, matching
+    /// `BencodeMut::encode_with`. Leaves are in list order, and sorted key order for dictionaries.
+    /// Useful for indexing text such as names, comments, and file paths without knowing the layout.
+    fn byte_leaves(&self) -> Vec<(Vec<&[u8]>, &[u8])>
+        where Self::BKey: AsRef<[u8]> {
+        let mut leaves = Vec::new();
+
+        collect_byte_leaves(self, &mut Vec::new(), &mut leaves);
+
+        leaves
+    }
+
+    /// Name of the bencode type, useful for error messages.
+    fn type_name(&self) -> &'static str {
+        match self.kind() {
+            BencodeRefKind::Int(_)   => "Integer",
+            BencodeRefKind::Bytes(_) => "Bytes",
+            BencodeRefKind::List(_)  => "List",
+            BencodeRefKind::Dict(_)  => "Dictionary"
+        }
+    }
+}
+
+/// Push every byte string within the bencode, along with its path, onto the leaves.
+fn collect_byte_leaves<'a, B>(bencode: &'a B, path: &mut Vec<&'a [u8]>, leaves: &mut Vec<(Vec<&'a [u8]>, &'a [u8])>)
+    where B: BRefAccess, B::BKey: AsRef<[u8]> {
+    match bencode.kind() {
+        BencodeRefKind::Int(_)   => (),
+        BencodeRefKind::Bytes(n) => leaves.push((path.clone(), n)),
+        BencodeRefKind::List(n)  => {
+            for value in n {
+                collect_byte_leaves(value, path, leaves);
+            }
+        },
+        BencodeRefKind::Dict(n)  => {
+            for (key, value) in n.to_list() {
+                path.push(key.as_ref());
+                collect_byte_leaves(value, path, leaves);
+                path.pop();
+            }
+        }
+    }
 }
 
 /// Trait for extended read access to some bencode type.
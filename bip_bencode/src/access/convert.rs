@@ -1,5 +1,7 @@
+use std::convert::TryFrom;
+
 use access::bencode::BRefAccessExt;
-use access::bencode::BRefAccess;
+use access::bencode::{BRefAccess, BencodeRefKind};
 use error::{BencodeConvertErrorKind, BencodeConvertError};
 use access::dict::BDictAccess;
 use access::list::BListAccess;
@@ -10,18 +12,14 @@ pub trait BConvertExt: BConvert {
     fn convert_bytes_ext<'a, B, E>(&self, bencode: B, error_key: E) -> Result<&'a [u8], Self::Error>
         where B: BRefAccessExt<'a>, E: AsRef<[u8]>
     {
-        bencode.bytes_ext().ok_or(self.handle_error(BencodeConvertError::from_kind(BencodeConvertErrorKind::WrongType{
-            key: error_key.as_ref().to_owned(), expected_type: "Bytes".to_owned()
-        })))
+        bencode.bytes_ext().ok_or_else(|| self.handle_error(wrong_type_error(&bencode, error_key.as_ref(), "Bytes")))
     }
 
     /// See BConvert::convert_str.
     fn convert_str_ext<'a, B, E>(&self, bencode: &B, error_key: E) -> Result<&'a str, Self::Error>
         where B: BRefAccessExt<'a>, E: AsRef<[u8]>
     {
-        bencode.str_ext().ok_or(self.handle_error(BencodeConvertError::from_kind(BencodeConvertErrorKind::WrongType{
-            key: error_key.as_ref().to_owned(), expected_type: "UTF-8 Bytes".to_owned()
-        })))
+        bencode.str_ext().ok_or_else(|| self.handle_error(wrong_type_error(&bencode, error_key.as_ref(), "UTF-8 Bytes")))
     }
 
     /// See BConvert::lookup_and_convert_bytes.
@@ -52,9 +50,7 @@ pub trait BConvert {
     fn convert_int<B, E>(&self, bencode: B, error_key: E) -> Result<i64, Self::Error>
         where B: BRefAccess, E: AsRef<[u8]>
     {
-        bencode.int().ok_or(self.handle_error(BencodeConvertError::from_kind(BencodeConvertErrorKind::WrongType{
-            key: error_key.as_ref().to_owned(), expected_type: "Integer".to_owned()
-        })))
+        bencode.int().ok_or_else(|| self.handle_error(wrong_type_error(&bencode, error_key.as_ref(), "Integer")))
     }
 
     /// Attempt to convert the given bencode value into bytes.
@@ -63,9 +59,17 @@ pub trait BConvert {
     fn convert_bytes<'a, B, E>(&self, bencode: &'a B, error_key: E) -> Result<&'a [u8], Self::Error>
         where B: BRefAccess, E: AsRef<[u8]>
     {
-        bencode.bytes().ok_or(self.handle_error(BencodeConvertError::from_kind(BencodeConvertErrorKind::WrongType{
-            key: error_key.as_ref().to_owned(), expected_type: "Bytes".to_owned()
-        })))
+        bencode.bytes().ok_or_else(|| self.handle_error(wrong_type_error(&bencode, error_key.as_ref(), "Bytes")))
+    }
+
+    /// Attempt to convert the given bencode value into exactly `N` bytes.
+    ///
+    /// Error key is used to generate an appropriate error message should the operation return an error.
+    fn convert_fixed_bytes<'a, B, E, const N: usize>(&self, bencode: &'a B, error_key: E) -> Result<&'a [u8; N], Self::Error>
+        where B: BRefAccess, E: AsRef<[u8]>
+    {
+        bencode.bytes().and_then(|n| <&[u8; N]>::try_from(n).ok())
+            .ok_or_else(|| self.handle_error(wrong_type_error(&bencode, error_key.as_ref(), &format!("Bytes Of Length {}", N))))
     }
 
     /// Attempt to convert the given bencode value into a UTF-8 string.
@@ -74,9 +78,7 @@ pub trait BConvert {
     fn convert_str<'a, B, E>(&self, bencode: &'a B, error_key: E) -> Result<&'a str, Self::Error>
         where B: BRefAccess, E: AsRef<[u8]>
     {
-        bencode.str().ok_or(self.handle_error(BencodeConvertError::from_kind(BencodeConvertErrorKind::WrongType{
-            key: error_key.as_ref().to_owned(), expected_type: "UTF-8 Bytes".to_owned()
-        })))
+        bencode.str().ok_or_else(|| self.handle_error(wrong_type_error(&bencode, error_key.as_ref(), "UTF-8 Bytes")))
     }
 
     /// Attempty to convert the given bencode value into a list.
@@ -85,9 +87,7 @@ pub trait BConvert {
     fn convert_list<'a, B, E>(&self, bencode: &'a B, error_key: E) -> Result<&'a BListAccess<B::BType>, Self::Error>
         where B: BRefAccess, E: AsRef<[u8]>
     {
-        bencode.list().ok_or(self.handle_error(BencodeConvertError::from_kind(BencodeConvertErrorKind::WrongType{
-            key: error_key.as_ref().to_owned(), expected_type: "List".to_owned()
-        })))
+        bencode.list().ok_or_else(|| self.handle_error(wrong_type_error(&bencode, error_key.as_ref(), "List")))
     }
 
     /// Attempt to convert the given bencode value into a dictionary.
@@ -96,9 +96,7 @@ pub trait BConvert {
     fn convert_dict<'a, B, E>(&self, bencode: &'a B, error_key: E) -> Result<&'a BDictAccess<B::BKey, B::BType>, Self::Error>
         where B: BRefAccess, E: AsRef<[u8]>
     {
-        bencode.dict().ok_or(self.handle_error(BencodeConvertError::from_kind(BencodeConvertErrorKind::WrongType{
-            key: error_key.as_ref().to_owned(), expected_type: "Dictionary".to_owned()
-        })))
+        bencode.dict().ok_or_else(|| self.handle_error(wrong_type_error(&bencode, error_key.as_ref(), "Dictionary")))
     }
 
     /// Look up a value in a dictionary of bencoded values using the given key.
@@ -113,6 +111,34 @@ pub trait BConvert {
         }
     }
 
+    /// Look up a value nested within dictionaries by walking the given path of keys.
+    ///
+    /// Lists are not crossed, every value along the path except the last must be a dictionary.
+    /// Error keys are made up of the path segments walked so far joined by `/`, so that the
+    /// segment that failed can be identified.
+    fn lookup_path<'a, B, K>(&self, root: &'a B, path: &[K]) -> Result<&'a B, Self::Error>
+        where B: BRefAccess<BType=B>, K: AsRef<[u8]>
+    {
+        let mut current = root;
+        let mut walked_path = Vec::new();
+
+        for segment in path {
+            let dictionary = try!(self.convert_dict(current, &walked_path));
+
+            if !walked_path.is_empty() {
+                walked_path.push(b'/');
+            }
+            walked_path.extend_from_slice(segment.as_ref());
+
+            current = match dictionary.lookup(segment.as_ref()) {
+                Some(n) => n,
+                None    => return Err(self.handle_error(BencodeConvertError::from_kind(BencodeConvertErrorKind::MissingKey{ key: walked_path }))),
+            };
+        }
+
+        Ok(current)
+    }
+
     /// Combines a lookup operation on the given key with a conversion of the value, if found, to an integer.
     fn lookup_and_convert_int<B, K1, K2>(&self, dictionary: &BDictAccess<K1, B>, key: K2) -> Result<i64, Self::Error>
         where B: BRefAccess, K2: AsRef<[u8]>
@@ -127,6 +153,13 @@ pub trait BConvert {
         self.convert_bytes(try!(self.lookup(dictionary, &key)), &key)
     }
 
+    /// Combines a lookup operation on the given key with a conversion of the value, if found, to exactly `N` bytes.
+    fn lookup_and_convert_fixed_bytes<'a, B, K1, K2, const N: usize>(&self, dictionary: &'a BDictAccess<K1, B>, key: K2) -> Result<&'a [u8; N], Self::Error>
+        where B: BRefAccess, K2: AsRef<[u8]>
+    {
+        self.convert_fixed_bytes(try!(self.lookup(dictionary, &key)), &key)
+    }
+
     /// Combines a lookup operation on the given key with a conversion of the value, if found, to a UTF-8 string.
     fn lookup_and_convert_str<'a, B, K1, K2>(&self, dictionary: &'a BDictAccess<K1, B>, key: K2) -> Result<&'a str, Self::Error>
         where B: BRefAccess, K2: AsRef<[u8]>
@@ -134,6 +167,66 @@ pub trait BConvert {
         self.convert_str(try!(self.lookup(dictionary, &key)), &key)
     }
 
+    /// Combines a lookup operation on the given key with a conversion of the value, if found, to an integer.
+    ///
+    /// Returns the default value if the key is missing, but still returns an error if the value has the wrong type.
+    fn lookup_and_convert_int_or<B, K1, K2>(&self, dictionary: &BDictAccess<K1, B>, key: K2, default: i64) -> Result<i64, Self::Error>
+        where B: BRefAccess, K2: AsRef<[u8]>
+    {
+        match dictionary.lookup(key.as_ref()) {
+            Some(n) => self.convert_int(n, &key),
+            None    => Ok(default)
+        }
+    }
+
+    /// Combines a lookup operation on the given key with a conversion of the value, if found, to a series of bytes.
+    ///
+    /// Returns the default value if the key is missing, but still returns an error if the value has the wrong type.
+    fn lookup_and_convert_bytes_or<'a, B, K1, K2>(&self, dictionary: &'a BDictAccess<K1, B>, key: K2, default: &'a [u8]) -> Result<&'a [u8], Self::Error>
+        where B: BRefAccess, K2: AsRef<[u8]>
+    {
+        match dictionary.lookup(key.as_ref()) {
+            Some(n) => self.convert_bytes(n, &key),
+            None    => Ok(default)
+        }
+    }
+
+    /// Combines a lookup operation on the given key with a conversion of the value, if found, to an integer.
+    ///
+    /// Returns None if the key is missing, but still returns an error if the value has the wrong type.
+    fn lookup_and_convert_int_opt<B, K1, K2>(&self, dictionary: &BDictAccess<K1, B>, key: K2) -> Result<Option<i64>, Self::Error>
+        where B: BRefAccess, K2: AsRef<[u8]>
+    {
+        match dictionary.lookup(key.as_ref()) {
+            Some(n) => self.convert_int(n, &key).map(Some),
+            None    => Ok(None)
+        }
+    }
+
+    /// Combines a lookup operation on the given key with a conversion of the value, if found, to a list.
+    ///
+    /// Returns None if the key is missing, but still returns an error if the value has the wrong type.
+    fn lookup_and_convert_list_opt<'a, B, K1, K2>(&self, dictionary: &'a BDictAccess<K1, B>, key: K2) -> Result<Option<&'a BListAccess<B::BType>>, Self::Error>
+        where B: BRefAccess, K2: AsRef<[u8]>
+    {
+        match dictionary.lookup(key.as_ref()) {
+            Some(n) => self.convert_list(n, &key).map(Some),
+            None    => Ok(None)
+        }
+    }
+
+    /// Combines a lookup operation on the given key with a conversion of the value, if found, to a dictionary.
+    ///
+    /// Returns None if the key is missing, but still returns an error if the value has the wrong type.
+    fn lookup_and_convert_dict_opt<'a, B, K1, K2>(&self, dictionary: &'a BDictAccess<K1, B>, key: K2) -> Result<Option<&'a BDictAccess<B::BKey, B::BType>>, Self::Error>
+        where B: BRefAccess, K2: AsRef<[u8]>
+    {
+        match dictionary.lookup(key.as_ref()) {
+            Some(n) => self.convert_dict(n, &key).map(Some),
+            None    => Ok(None)
+        }
+    }
+
     /// Combines a lookup operation on the given key with a conversion of the value, if found, to a list.
     fn lookup_and_convert_list<'a, B, K1, K2>(&self, dictionary: &'a BDictAccess<K1, B>, key: K2) -> Result<&'a BListAccess<B::BType>, Self::Error>
         where B: BRefAccess, K2: AsRef<[u8]>
@@ -148,3 +241,26 @@ pub trait BConvert {
         self.convert_dict(try!(self.lookup(dictionary, &key)), &key)
     }
 }
+
+/// Maximum number of bytes rendered when describing a bytes value in an error.
+const MAX_FOUND_BYTES_LEN: usize = 16;
+
+/// Create a wrong type error describing both the expected type and the value actually found.
+fn wrong_type_error<B>(bencode: &B, error_key: &[u8], expected_type: &str) -> BencodeConvertError
+    where B: BRefAccess {
+    let found = match bencode.kind() {
+        BencodeRefKind::Int(n)   => format!("Integer {}", n),
+        BencodeRefKind::Bytes(n) => {
+            let render_len = if n.len() > MAX_FOUND_BYTES_LEN { MAX_FOUND_BYTES_LEN } else { n.len() };
+            let ellipsis = if n.len() > render_len { "..." } else { "" };
+
+            format!("Bytes Of Length {} {:?}{}", n.len(), String::from_utf8_lossy(&n[..render_len]), ellipsis)
+        },
+        BencodeRefKind::List(n)  => format!("List Of Length {}", n.len()),
+        BencodeRefKind::Dict(n)  => format!("Dictionary Of Length {}", n.len())
+    };
+
+    BencodeConvertError::from_kind(BencodeConvertErrorKind::WrongType{
+        key: error_key.to_owned(), expected_type: expected_type.to_owned(), found: found
+    })
+}
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 use std::collections::BTreeMap;
+use std::mem;
 
 /// Trait for working with generic map data structures.
 pub trait BDictAccess<K, V> {
@@ -17,6 +18,27 @@ pub trait BDictAccess<K, V> {
 
     /// Remove a value from the dictionary and return it.
     fn remove(&mut self, key: &[u8]) -> Option<V>;
+
+    /// Number of key/value pairs in the dictionary.
+    fn len(&self) -> usize;
+
+    /// Whether or not the dictionary contains no key/value pairs.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Whether or not the dictionary contains the given key.
+    fn contains_key(&self, key: &[u8]) -> bool {
+        self.lookup(key).is_some()
+    }
+
+    /// Replace the value for an existing key in place and return the old value.
+    ///
+    /// Unlike a remove followed by an insert, the key keeps its position. Returns None,
+    /// without inserting, if the key is not present.
+    fn replace(&mut self, key: &[u8], value: V) -> Option<V> {
+        self.lookup_mut(key).map(|old_value| mem::replace(old_value, value))
+    }
 }
 
 impl<'a, V> BDictAccess<&'a [u8], V> for BTreeMap<&'a [u8], V> {
@@ -39,6 +61,10 @@ impl<'a, V> BDictAccess<&'a [u8], V> for BTreeMap<&'a [u8], V> {
     fn remove(&mut self, key: &[u8]) -> Option<V> {
         self.remove(key)
     }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
 }
 
 impl<'a, V> BDictAccess<Cow<'a, [u8]>, V> for BTreeMap<Cow<'a, [u8]>, V> {
@@ -61,4 +87,8 @@ impl<'a, V> BDictAccess<Cow<'a, [u8]>, V> for BTreeMap<Cow<'a, [u8]>, V> {
     fn remove(&mut self, key: &[u8]) -> Option<V> {
         self.remove(key)
     }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
 }
\ No newline at end of file
@@ -1,3 +1,5 @@
+use std::cmp::Ordering;
+use std::mem;
 use std::ops::{Index, IndexMut};
 
 /// Trait for working with generic list data structures.
@@ -19,6 +21,50 @@ pub trait BListAccess<V> {
 
     /// Get the length of the list.
     fn len(&self) -> usize;
+
+    /// Push each element yielded by the iterator to the back of the list.
+    fn extend_from_iter(&mut self, items: &mut Iterator<Item=V>) {
+        for item in items {
+            self.push(item);
+        }
+    }
+
+    /// Move all elements of the other list to the back of this list, leaving the other list empty.
+    fn append(&mut self, other: &mut BListAccess<V>) {
+        let mut drained = Vec::with_capacity(other.len());
+        // Remove from the back to avoid shifting the remaining elements
+        for index in (0..other.len()).rev() {
+            drained.extend(other.remove(index));
+        }
+
+        self.extend_from_iter(&mut drained.into_iter().rev());
+    }
+
+    /// Replace the list element at the given index and return the old element.
+    ///
+    /// Returns None, without inserting, if the index is out of bounds.
+    fn replace(&mut self, index: usize, item: V) -> Option<V> {
+        self.get_mut(index).map(|old_item| mem::replace(old_item, item))
+    }
+
+    /// Sort the list in place using the given comparator.
+    ///
+    /// The sort is stable, so elements that compare equal keep their relative order.
+    fn sort_by(&mut self, compare: &mut FnMut(&V, &V) -> Ordering);
+
+    /// Binary search a sorted list using the given comparator.
+    ///
+    /// Mirrors `slice::binary_search_by`, if the list is not sorted the result is unspecified.
+    fn binary_search_by(&self, compare: &mut FnMut(&V) -> Ordering) -> Result<usize, usize>;
+}
+
+impl<'b, V> BListAccess<V> + 'b {
+    /// Iterator over the list elements, in list order.
+    ///
+    /// Pair with `Iterator::enumerate` to report the index of an element.
+    pub fn iter<'a>(&'a self) -> BListIter<'a, V> where V: 'a {
+        BListIter{ index: 0, access: self }
+    }
 }
 
 impl<'a, V: 'a> Index<usize> for &'a BListAccess<V> {
@@ -52,6 +98,7 @@ impl<'a, V: 'a> IntoIterator for &'a BListAccess<V> {
     }
 }
 
+/// Iterator over the elements of a `BListAccess`, in list order.
 pub struct BListIter<'a, V: 'a> {
     index:  usize,
     access: &'a BListAccess<V>
@@ -99,4 +146,12 @@ impl<V> BListAccess<V> for Vec<V> {
     fn len(&self) -> usize {
         Vec::len(self)
     }
+
+    fn sort_by(&mut self, compare: &mut FnMut(&V, &V) -> Ordering) {
+        (&mut self[..]).sort_by(|a, b| compare(a, b))
+    }
+
+    fn binary_search_by(&self, compare: &mut FnMut(&V) -> Ordering) -> Result<usize, usize> {
+        (&self[..]).binary_search_by(|a| compare(a))
+    }
 }
\ No newline at end of file
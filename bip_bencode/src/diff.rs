@@ -0,0 +1,144 @@
+use std::cmp;
+
+use access::bencode::{BRefAccess, BencodeRefKind};
+use access::dict::BDictAccess;
+use access::list::BListAccess;
+use mutable::bencode_mut::{self, BencodeMut};
+
+/// Single structural difference between two bencode objects.
+///
+/// Paths are rooted at `root`, with list indices and dictionary keys appended, such as `root["info"]["files"][1]`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum BencodeDiff {
+    /// Value is present in the second bencode, but not the first.
+    Added { path: String, value: BencodeMut<'static> },
+    /// Value is present in the first bencode, but not the second.
+    Removed { path: String, value: BencodeMut<'static> },
+    /// Value is present in both, but differs in type or value.
+    Changed { path: String, old: BencodeMut<'static>, new: BencodeMut<'static> },
+}
+
+impl BencodeDiff {
+    /// Path to the value that differs.
+    pub fn path(&self) -> &str {
+        match *self {
+            BencodeDiff::Added{ ref path, .. }   => path,
+            BencodeDiff::Removed{ ref path, .. } => path,
+            BencodeDiff::Changed{ ref path, .. } => path
+        }
+    }
+}
+
+/// Compute the structural differences between two bencode objects.
+///
+/// Lists are compared by index and dictionaries are compared by key. Differences are
+/// ordered depth first, with dictionary keys visited in sorted order.
+pub fn bencode_diff<A, B>(a: &A, b: &B) -> Vec<BencodeDiff>
+    where A: BRefAccess, A::BKey: AsRef<[u8]>, B: BRefAccess, B::BKey: AsRef<[u8]> {
+    let mut diffs = Vec::new();
+    let mut path = String::from("root");
+
+    diff(a, b, &mut path, &mut diffs);
+
+    diffs
+}
+
+fn diff<A, B>(a: &A, b: &B, path: &mut String, diffs: &mut Vec<BencodeDiff>)
+    where A: BRefAccess, A::BKey: AsRef<[u8]>, B: BRefAccess, B::BKey: AsRef<[u8]> {
+    let is_same = match (a.kind(), b.kind()) {
+        (BencodeRefKind::Int(a), BencodeRefKind::Int(b))     => a == b,
+        (BencodeRefKind::Bytes(a), BencodeRefKind::Bytes(b)) => a == b,
+        (BencodeRefKind::List(a), BencodeRefKind::List(b))   => { diff_list(a, b, path, diffs); true },
+        (BencodeRefKind::Dict(a), BencodeRefKind::Dict(b))   => { diff_dict(a, b, path, diffs); true },
+        _ => false
+    };
+
+    if !is_same {
+        diffs.push(BencodeDiff::Changed{ path: path.clone(), old: bencode_mut::to_owned_mut(a),
+                                         new: bencode_mut::to_owned_mut(b) });
+    }
+}
+
+fn diff_list<A, B>(a: &BListAccess<A>, b: &BListAccess<B>, path: &mut String, diffs: &mut Vec<BencodeDiff>)
+    where A: BRefAccess, A::BKey: AsRef<[u8]>, B: BRefAccess, B::BKey: AsRef<[u8]> {
+    let max_len = cmp::max(a.len(), b.len());
+
+    for index in 0..max_len {
+        let path_len = path.len();
+        path.push_str(&format!("[{}]", index));
+
+        match (a.get(index), b.get(index)) {
+            (Some(a), Some(b)) => diff(a, b, path, diffs),
+            (Some(a), None)    => diffs.push(BencodeDiff::Removed{ path: path.clone(), value: bencode_mut::to_owned_mut(a) }),
+            (None, Some(b))    => diffs.push(BencodeDiff::Added{ path: path.clone(), value: bencode_mut::to_owned_mut(b) }),
+            (None, None)       => ()
+        }
+        path.truncate(path_len);
+    }
+}
+
+fn diff_dict<KA, A, KB, B>(a: &BDictAccess<KA, A>, b: &BDictAccess<KB, B>, path: &mut String, diffs: &mut Vec<BencodeDiff>)
+    where KA: AsRef<[u8]>, A: BRefAccess, A::BKey: AsRef<[u8]>, KB: AsRef<[u8]>, B: BRefAccess, B::BKey: AsRef<[u8]> {
+    let mut keys: Vec<&[u8]> = a.to_list().into_iter().map(|(key, _)| key.as_ref())
+        .chain(b.to_list().into_iter().map(|(key, _)| key.as_ref()))
+        .collect();
+    (&mut keys[..]).sort();
+    keys.dedup();
+
+    for key in keys {
+        let path_len = path.len();
+        path.push_str(&format!("[{:?}]", String::from_utf8_lossy(key)));
+
+        match (a.lookup(key), b.lookup(key)) {
+            (Some(a), Some(b)) => diff(a, b, path, diffs),
+            (Some(a), None)    => diffs.push(BencodeDiff::Removed{ path: path.clone(), value: bencode_mut::to_owned_mut(a) }),
+            (None, Some(b))    => diffs.push(BencodeDiff::Added{ path: path.clone(), value: bencode_mut::to_owned_mut(b) }),
+            (None, None)       => ()
+        }
+        path.truncate(path_len);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::default::Default;
+
+    use diff::BencodeDiff;
+    use mutable::bencode_mut::BencodeMut;
+    use reference::bencode_ref::BencodeRef;
+    use reference::decode_opt::BDecodeOpt;
+
+    #[test]
+    fn positive_diff_equal() {
+        let bytes = b"d1:ali1ei2ee1:bi5ee";
+        let a = BencodeRef::decode(&bytes[..], BDecodeOpt::default()).unwrap();
+        let b = BencodeRef::decode(&bytes[..], BDecodeOpt::default()).unwrap();
+
+        assert!(super::bencode_diff(&a, &b).is_empty());
+    }
+
+    #[test]
+    fn positive_diff_added_removed_changed() {
+        let a_bytes = b"d1:ali1ei2ee1:bi5ee";
+        let b_bytes = b"d1:ali1ei3ei4ee1:c0:e";
+        let a = BencodeRef::decode(&a_bytes[..], BDecodeOpt::default()).unwrap();
+        let b = BencodeRef::decode(&b_bytes[..], BDecodeOpt::default()).unwrap();
+
+        let diffs = super::bencode_diff(&a, &b);
+        assert_eq!(vec![BencodeDiff::Changed{ path: "root[\"a\"][1]".to_owned(), old: BencodeMut::new_int(2), new: BencodeMut::new_int(3) },
+                        BencodeDiff::Added{ path: "root[\"a\"][2]".to_owned(), value: BencodeMut::new_int(4) },
+                        BencodeDiff::Removed{ path: "root[\"b\"]".to_owned(), value: BencodeMut::new_int(5) },
+                        BencodeDiff::Added{ path: "root[\"c\"]".to_owned(), value: BencodeMut::new_bytes((&b""[..]).into()) }],
+                   diffs);
+    }
+
+    #[test]
+    fn positive_diff_type_changed() {
+        let a = BencodeRef::decode(&b"li1ee"[..], BDecodeOpt::default()).unwrap();
+        let b = BencodeMut::new_int(1);
+
+        let diffs = super::bencode_diff(&a, &b);
+        assert_eq!(1, diffs.len());
+        assert_eq!("root", diffs[0].path());
+    }
+}
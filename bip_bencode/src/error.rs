@@ -73,6 +73,33 @@ error_chain! {
             description("Invalid Recursion Limit Exceeded")
             display("Invalid Recursion Limit Exceeded At {:?} For Limit {:?}", pos, max)
         }
+        CollectionTooLarge {
+            pos: usize,
+            max: usize
+        } {
+            description("Collection Too Large Found")
+            display("Collection Too Large Found At {:?} For Limit {:?}", pos, max)
+        }
+        StringTooLarge {
+            pos: usize,
+            max: usize
+        } {
+            description("String Too Large Found")
+            display("String Too Large Found At {:?} For Limit {:?}", pos, max)
+        }
+        InvalidNestedType {
+            found: String
+        } {
+            description("Invalid Nested Bencode Found In Non Bytes Type")
+            display("Invalid Nested Bencode Found In Non Bytes Type {}", found)
+        }
+        InvalidRoundTrip {
+            path: String,
+            details: String
+        } {
+            description("Invalid Round Trip Found")
+            display("Invalid Round Trip Found At {} For {}", path, details)
+        }
     }
 }
 
@@ -90,10 +117,41 @@ error_chain! {
         }
         WrongType {
             key: Vec<u8>,
-            expected_type: String
+            expected_type: String,
+            found: String
          } {
             description("Wrong Type In Bencode")
-            display("Wrong Type In Bencode For {:?} Expected Type {}", key, expected_type)
+            display("Wrong Type In Bencode For {:?} Expected Type {} Found {}", String::from_utf8_lossy(key), expected_type, found)
+        }
+    }
+}
+
+error_chain! {
+    types {
+        BencodeEncodeError, BencodeEncodeErrorKind, BencodeEncodeResultExt, BencodeEncodeResult;
+    }
+
+    errors {
+        BufferTooSmall {
+            needed: usize,
+            available: usize
+         } {
+            description("Buffer Too Small For Encoded Bencode")
+            display("Buffer Too Small For Encoded Bencode Needed {:?} Available {:?}", needed, available)
+        }
+        InvalidEncoding {
+            details: String
+         } {
+            description("Encoded Bencode Failed Validation")
+            display("Encoded Bencode Failed Validation With {}", details)
+        }
+        InvalidValue {
+            path: Vec<Vec<u8>>,
+            details: String
+         } {
+            description("Bencode Value Failed Validation")
+            display("Bencode Value At {:?} Failed Validation With {}",
+                    path.iter().map(|key| String::from_utf8_lossy(key).into_owned()).collect::<Vec<String>>(), details)
         }
     }
 }
@@ -40,9 +40,11 @@ extern crate error_chain;
 
 mod access;
 mod cow;
+mod diff;
 mod mutable;
 mod reference;
 mod error;
+mod round_trip;
 
 /// Traits for implementation functionality.
 pub mod inner {
@@ -57,13 +59,17 @@ pub mod ext {
 
 pub use reference::bencode_ref::{BencodeRef};
 pub use mutable::bencode_mut::{BencodeMut};
+pub use mutable::encode::{bencode_encode, EncodeAction};
 pub use access::bencode::{BRefAccess, BencodeRefKind, BMutAccess, BencodeMutKind};
 pub use access::convert::{BConvert};
 pub use access::dict::BDictAccess;
 pub use access::list::BListAccess;
-pub use reference::decode_opt::BDecodeOpt;
+pub use round_trip::assert_round_trip;
+pub use diff::{bencode_diff, BencodeDiff};
+pub use reference::decode_opt::{BDecodeOpt, DuplicatePolicy};
 pub use error::{BencodeParseError, BencodeParseErrorKind, BencodeParseResult};
 pub use error::{BencodeConvertError, BencodeConvertErrorKind, BencodeConvertResult};
+pub use error::{BencodeEncodeError, BencodeEncodeErrorKind, BencodeEncodeResult};
 
 const BEN_END: u8 = b'e';
 const DICT_START: u8 = b'd';
@@ -139,3 +145,15 @@ macro_rules! ben_int {
         }
     }
 }
+
+/// Construct a `BencodeMut` integer by supplying a `bool`, `true` maps to `1` and `false` maps to `0`.
+#[macro_export]
+macro_rules! ben_bool {
+    ( $ben:expr ) => {
+        {
+            use bip_bencode::{BencodeMut};
+
+            BencodeMut::new_int(if $ben { 1 } else { 0 })
+        }
+    }
+}
@@ -1,11 +1,17 @@
 use std::borrow::Cow;
+use std::cmp::Ordering;
 use std::collections::BTreeMap;
+use std::iter::FromIterator;
 use std::str;
 
-use access::bencode::{BencodeMutKind, BMutAccess, BRefAccess, BencodeRefKind};
+use access::bencode::{BencodeMutKind, BMutAccess, BRefAccess, BRefAccessExt, BencodeRefKind};
 use access::dict::BDictAccess;
 use access::list::BListAccess;
-use mutable::encode;
+use cow::BCowConvert;
+use error::{BencodeEncodeError, BencodeEncodeErrorKind, BencodeEncodeResult};
+use mutable::encode::{self, EncodeAction, EncodeSink, SliceSink};
+use reference::bencode_ref::BencodeRef;
+use reference::decode_opt::BDecodeOpt;
 
 /// Bencode object that holds references to the underlying data.
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
@@ -21,6 +27,14 @@ pub enum InnerBencodeMut<'a> {
 }
 
 /// `BencodeMut` object that stores references to some data.
+///
+/// Equality and hashing are structural, dictionaries are compared and hashed in sorted
+/// key order. Both operations walk the entire tree, so hashing a large dictionary is
+/// proportional to the size of that dictionary.
+///
+/// Cloning copies the entire tree, so a template can be cloned and then modified without
+/// affecting the original. Borrowed bytes and keys are cloned as borrows of the same data.
+/// Dictionary keys are always kept in sorted order, so encoding a clone is deterministic.
 #[derive(Debug, Eq, PartialEq, Clone, Hash)]
 pub struct BencodeMut<'a> {
     inner:   InnerBencodeMut<'a>
@@ -41,6 +55,13 @@ impl<'a> BencodeMut<'a> {
         BencodeMut::new(InnerBencodeMut::Bytes(value.into()))
     }
 
+    /// Create a new `BencodeMut` representing a `[u8]`, taking ownership of the given bytes.
+    ///
+    /// The bytes are moved in without copying, which matters for large values such as a pieces buffer.
+    pub fn new_bytes_owned(value: Vec<u8>) -> BencodeMut<'a> {
+        BencodeMut::new(InnerBencodeMut::Bytes(Cow::Owned(value)))
+    }
+
     /// Create a new `BencodeMut` representing a `BListAccess`.
     pub fn new_list() -> BencodeMut<'a> {
         BencodeMut::new(InnerBencodeMut::List(Vec::new()))
@@ -51,14 +72,255 @@ impl<'a> BencodeMut<'a> {
         BencodeMut::new(InnerBencodeMut::Dict(BTreeMap::new()))
     }
 
+    /// Exact number of bytes that encoding this `BencodeMut` will produce.
+    ///
+    /// Walks the entire tree, so the cost is proportional to the number of nodes.
+    pub fn encoded_len(&self) -> usize {
+        encode::encoded_len(self)
+    }
+
+    /// Create a new `BencodeMut` from the given bencode, borrowing its byte strings and keys.
+    ///
+    /// Only the list and dictionary structure is allocated, no bytes are copied, so editing a single
+    /// value in a large torrent does not duplicate the pieces string. Values inserted or replaced
+    /// afterwards are stored as given, while everything else keeps borrowing from the decoded bytes.
+    pub fn borrowing(bencode: &BencodeRef<'a>) -> BencodeMut<'a> {
+        match bencode.kind() {
+            BencodeRefKind::Int(n)  => BencodeMut::new_int(n),
+            BencodeRefKind::Bytes(_) => BencodeMut::new_bytes(Cow::Borrowed(bencode.bytes_ext().unwrap())),
+            BencodeRefKind::List(n) => BencodeMut::list_from_iter(n.into_iter().map(BencodeMut::borrowing)),
+            BencodeRefKind::Dict(_) => {
+                BencodeMut::dict_from_iter(bencode.entries_sorted().unwrap().into_iter()
+                    .map(|(key, value)| (key, BencodeMut::borrowing(value))))
+            }
+        }
+    }
+
+    /// Create a new `BencodeMut` representing a `BListAccess` containing the given elements.
+    pub fn list_from_iter<I>(iter: I) -> BencodeMut<'a>
+        where I: IntoIterator<Item=BencodeMut<'a>> {
+        BencodeMut::new(InnerBencodeMut::List(iter.into_iter().collect()))
+    }
+
+    /// Create a new `BencodeMut` representing a `BDictAccess` containing the given entries.
+    ///
+    /// Later entries overwrite earlier entries with the same key. Borrowed keys, such as
+    /// `&'static str`, are stored without being copied.
+    pub fn dict_from_iter<I, K>(iter: I) -> BencodeMut<'a>
+        where I: IntoIterator<Item=(K, BencodeMut<'a>)>, K: BCowConvert<'a> {
+        BencodeMut::new(InnerBencodeMut::Dict(iter.into_iter().map(|(key, value)| (key.convert(), value)).collect()))
+    }
+
     /// Encode the `BencodeMut` into a buffer representing the bencode.
     pub fn encode(&self) -> Vec<u8> {
-        let mut buffer = Vec::new();
+        let mut buffer = Vec::with_capacity(self.encoded_len());
 
         encode::encode(self, &mut buffer);
 
         buffer
     }
+
+    /// Encode the `BencodeMut` into a buffer, ordering dictionary keys with the given comparator.
+    ///
+    /// Bencode requires keys sorted by raw bytes, which is what `encode` does. This is only useful for
+    /// reproducing the output (and so the info hash) of an implementation that sorts keys differently.
+    pub fn encode_sorted_by<F>(&self, cmp: F) -> Vec<u8>
+        where F: Fn(&[u8], &[u8]) -> Ordering {
+        let mut buffer = Vec::with_capacity(self.encoded_len());
+
+        encode::encode_sorted_by(self, &mut buffer, &cmp);
+
+        buffer
+    }
+
+    /// Encode the `BencodeMut` into a buffer, letting the visitor keep, remove, or replace each value.
+    ///
+    /// The visitor is given the dictionary keys leading to each value, with list elements not adding to
+    /// the path, so the root value has an empty path. Removing the root value produces empty output.
+    /// Useful for redacting fields, such as a comment, without modifying the `BencodeMut`.
+    pub fn encode_with<'b, F>(&self, mut visit: F) -> Vec<u8>
+        where F: FnMut(&[&[u8]], &BencodeMut<'a>) -> EncodeAction<'b> {
+        let mut buffer = Vec::new();
+
+        encode::encode_with(self, &mut buffer, &[], &mut visit);
+
+        buffer
+    }
+
+    /// Encode the `BencodeMut` into the given buffer, returning the number of bytes written.
+    ///
+    /// Returns an error, without writing anything, if the buffer is smaller than `encoded_len`.
+    /// Encoding into the buffer does not allocate.
+    pub fn encode_into_slice(&self, buffer: &mut [u8]) -> BencodeEncodeResult<usize> {
+        let needed = self.encoded_len();
+        if buffer.len() < needed {
+            return Err(BencodeEncodeError::from_kind(BencodeEncodeErrorKind::BufferTooSmall{ needed: needed, available: buffer.len() }))
+        }
+
+        // SliceSink panics on overflow, the length check above guarantees the output fits
+        let mut sink = SliceSink::new(buffer);
+        self.encode_sorted_into(&mut sink);
+
+        Ok(sink.written())
+    }
+
+    /// Encode directly from the inner representation, without allocating.
+    ///
+    /// Dictionaries are stored in a `BTreeMap`, so keys are already in sorted order.
+    fn encode_sorted_into<S>(&self, sink: &mut S)
+        where S: EncodeSink {
+        match self.inner {
+            InnerBencodeMut::Int(n)       => encode::encode_int(n, sink),
+            InnerBencodeMut::Bytes(ref n) => encode::encode_bytes(n, sink),
+            InnerBencodeMut::List(ref n)  => {
+                sink.put(&[::LIST_START]);
+                for value in n.iter() {
+                    value.encode_sorted_into(sink);
+                }
+                sink.put(&[::BEN_END]);
+            },
+            InnerBencodeMut::Dict(ref n)  => {
+                sink.put(&[::DICT_START]);
+                for (key, value) in n.iter() {
+                    encode::encode_bytes(key, sink);
+                    value.encode_sorted_into(sink);
+                }
+                sink.put(&[::BEN_END]);
+            }
+        }
+    }
+
+    /// Encode the `BencodeMut`, then check that the output is valid, canonical bencode.
+    ///
+    /// The output is decoded again with key sort checking enabled, which rejects malformed
+    /// tokens such as zero padded or signed integers. Intended as a safeguard in test suites,
+    /// since it roughly doubles the cost of encoding.
+    pub fn encode_validated(&self) -> BencodeEncodeResult<Vec<u8>> {
+        let bytes = self.encode();
+
+        let opts = BDecodeOpt::new(::std::usize::MAX, true, true);
+        if let Err(error) = BencodeRef::decode(&bytes, opts) {
+            return Err(BencodeEncodeError::from_kind(BencodeEncodeErrorKind::InvalidEncoding{ details: error.to_string() }))
+        }
+
+        Ok(bytes)
+    }
+
+    /// Check that the `BencodeMut` would decode again with the default decode options.
+    ///
+    /// Dictionary keys are unique and sorted by construction, so the only structural problem is
+    /// nesting deeper than the default maximum recursion. See `validate_with` for custom checks.
+    pub fn validate(&self) -> BencodeEncodeResult<()> {
+        self.validate_with(|_, _| Ok(()))
+    }
+
+    /// Check the `BencodeMut` as in `validate`, also running the given check against each value.
+    ///
+    /// The check is given the dictionary keys leading to each value, as in `encode_with`. The first
+    /// problem found is returned as an `InvalidValue` error holding the path to the value.
+    pub fn validate_with<F>(&self, mut check: F) -> BencodeEncodeResult<()>
+        where F: FnMut(&[&[u8]], &BencodeMut<'a>) -> Result<(), String> {
+        validate_value(self, &mut Vec::new(), 0, &mut check)
+    }
+
+    /// Consume the `BencodeMut`, returning the `i64` if it is an integer.
+    pub fn into_int(self) -> Option<i64> {
+        match self.inner {
+            InnerBencodeMut::Int(n) => Some(n),
+            _ => None
+        }
+    }
+
+    /// Consume the `BencodeMut`, returning the owned bytes if it is a byte string.
+    ///
+    /// Borrowed bytes are copied, owned bytes are moved out without copying.
+    pub fn into_bytes(self) -> Option<Vec<u8>> {
+        match self.inner {
+            InnerBencodeMut::Bytes(n) => Some(n.into_owned()),
+            _ => None
+        }
+    }
+
+    /// Consume the `BencodeMut`, returning the elements if it is a list.
+    ///
+    /// Iterate over the returned `Vec` to move each element out without cloning.
+    pub fn into_list(self) -> Option<Vec<BencodeMut<'a>>> {
+        match self.inner {
+            InnerBencodeMut::List(n) => Some(n),
+            _ => None
+        }
+    }
+
+    /// Consume the `BencodeMut`, returning the entries if it is a dictionary.
+    ///
+    /// Iterate over the returned `BTreeMap` to move each (key, value) pair out, in sorted key order.
+    pub fn into_dict(self) -> Option<BTreeMap<Cow<'a, [u8]>, BencodeMut<'a>>> {
+        match self.inner {
+            InnerBencodeMut::Dict(n) => Some(n),
+            _ => None
+        }
+    }
+}
+
+/// Validate the given value and each of its children, depth first.
+fn validate_value<'a, 'b, F>(val: &'b BencodeMut<'a>, path: &mut Vec<&'b [u8]>, depth: usize, check: &mut F) -> BencodeEncodeResult<()>
+    where F: FnMut(&[&[u8]], &BencodeMut<'a>) -> Result<(), String> {
+    let invalid_value = |path: &[&[u8]], details: String| {
+        let path = path.iter().map(|key| key.to_vec()).collect();
+
+        BencodeEncodeError::from_kind(BencodeEncodeErrorKind::InvalidValue{ path: path, details: details })
+    };
+
+    let max_recursion = BDecodeOpt::default().max_recursion();
+    if depth >= max_recursion {
+        return Err(invalid_value(path, format!("Nesting Deeper Than {} Levels", max_recursion)))
+    }
+    try!(check(path, val).map_err(|details| invalid_value(path, details)));
+
+    match val.inner {
+        InnerBencodeMut::List(ref n) => {
+            for value in n.iter() {
+                try!(validate_value(value, path, depth + 1, check));
+            }
+        },
+        InnerBencodeMut::Dict(ref n) => {
+            for (key, value) in n.iter() {
+                path.push(key.as_ref());
+                try!(validate_value(value, path, depth + 1, check));
+                path.pop();
+            }
+        },
+        _ => ()
+    }
+
+    Ok(())
+}
+
+/// Create an owned `BencodeMut` by copying the given bencode.
+pub fn to_owned_mut<B>(bencode: &B) -> BencodeMut<'static>
+    where B: BRefAccess, B::BKey: AsRef<[u8]> {
+    match bencode.kind() {
+        BencodeRefKind::Int(n)   => BencodeMut::new_int(n),
+        BencodeRefKind::Bytes(n) => BencodeMut::new_bytes(n.to_vec().into()),
+        BencodeRefKind::List(n)  => BencodeMut::list_from_iter(n.into_iter().map(to_owned_mut)),
+        BencodeRefKind::Dict(n)  => {
+            BencodeMut::dict_from_iter(n.to_list().into_iter().map(|(key, value)| (key.as_ref().to_vec(), to_owned_mut(value))))
+        }
+    }
+}
+
+impl<'a> FromIterator<BencodeMut<'a>> for BencodeMut<'a> {
+    fn from_iter<I>(iter: I) -> BencodeMut<'a>
+        where I: IntoIterator<Item=BencodeMut<'a>> {
+        BencodeMut::list_from_iter(iter)
+    }
+}
+
+impl<'a, K> FromIterator<(K, BencodeMut<'a>)> for BencodeMut<'a> where K: BCowConvert<'a> {
+    fn from_iter<I>(iter: I) -> BencodeMut<'a>
+        where I: IntoIterator<Item=(K, BencodeMut<'a>)> {
+        BencodeMut::dict_from_iter(iter)
+    }
 }
 
 impl<'a> BRefAccess for BencodeMut<'a> {
@@ -142,8 +404,14 @@ impl<'a> BMutAccess for BencodeMut<'a> {
 
 #[cfg(test)]
 mod test {
-    use access::bencode::BMutAccess;
-    use mutable::bencode_mut::BencodeMut;
+    use std::borrow::Cow;
+
+    use access::bencode::{BMutAccess, BRefAccess};
+    use error::BencodeEncodeErrorKind;
+    use mutable::bencode_mut::{BencodeMut, InnerBencodeMut};
+    use mutable::encode::EncodeAction;
+    use reference::bencode_ref::BencodeRef;
+    use reference::decode_opt::BDecodeOpt;
 
     #[test]
     fn positive_int_encode() {
@@ -202,4 +470,302 @@ mod test {
         let dict_bytes = b"d3:asd6:asdasde";
         assert_eq!(&dict_bytes[..], &bencode_dict.encode()[..]);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn positive_list_sort_by() {
+        let mut bencode_list = BencodeMut::new_list();
+
+        {
+            let list_mut = bencode_list.list_mut().unwrap();
+            list_mut.push(BencodeMut::new_int(3));
+            list_mut.push(BencodeMut::new_int(1));
+            list_mut.push(BencodeMut::new_int(2));
+
+            list_mut.sort_by(&mut |a, b| a.int().cmp(&b.int()));
+        }
+
+        let list_bytes = b"li1ei2ei3ee";
+        assert_eq!(&list_bytes[..], &bencode_list.encode()[..]);
+    }
+
+    #[test]
+    fn positive_list_replace() {
+        let mut bencode_list = BencodeMut::list_from_iter(vec![BencodeMut::new_int(1), BencodeMut::new_int(2)]);
+
+        {
+            let list_mut = bencode_list.list_mut().unwrap();
+            assert_eq!(Some(BencodeMut::new_int(2)), list_mut.replace(1, BencodeMut::new_int(5)));
+            assert_eq!(None, list_mut.replace(2, BencodeMut::new_int(6)));
+        }
+
+        let list_bytes = b"li1ei5ee";
+        assert_eq!(&list_bytes[..], &bencode_list.encode()[..]);
+    }
+
+    #[test]
+    fn positive_list_extend_from_iter_append() {
+        let mut bencode_list = BencodeMut::list_from_iter(vec![BencodeMut::new_int(1)]);
+        let mut other_list = BencodeMut::list_from_iter(vec![BencodeMut::new_int(4), BencodeMut::new_int(5)]);
+
+        {
+            let list_mut = bencode_list.list_mut().unwrap();
+            list_mut.extend_from_iter(&mut vec![BencodeMut::new_int(2), BencodeMut::new_int(3)].into_iter());
+            list_mut.append(other_list.list_mut().unwrap());
+        }
+
+        assert_eq!(&b"li1ei2ei3ei4ei5ee"[..], &bencode_list.encode()[..]);
+        assert_eq!(&b"le"[..], &other_list.encode()[..]);
+    }
+
+    #[test]
+    fn positive_dict_replace() {
+        let mut bencode_dict = BencodeMut::dict_from_iter(vec![(b"a".to_vec(), BencodeMut::new_int(1)),
+                                                               (b"b".to_vec(), BencodeMut::new_int(2))]);
+
+        {
+            let dict_mut = bencode_dict.dict_mut().unwrap();
+            assert_eq!(Some(BencodeMut::new_int(1)), dict_mut.replace(b"a", BencodeMut::new_int(5)));
+            assert_eq!(None, dict_mut.replace(b"c", BencodeMut::new_int(6)));
+        }
+
+        let dict_bytes = b"d1:ai5e1:bi2ee";
+        assert_eq!(&dict_bytes[..], &bencode_dict.encode()[..]);
+    }
+
+    #[test]
+    fn positive_clone_is_independent() {
+        let template = BencodeMut::dict_from_iter(vec![("b", BencodeMut::list_from_iter(vec![BencodeMut::new_int(1)])),
+                                                       ("a", BencodeMut::new_int(2))]);
+
+        let mut cloned = template.clone();
+        {
+            let dict_mut = cloned.dict_mut().unwrap();
+            dict_mut.lookup_mut(b"b").unwrap().list_mut().unwrap().push(BencodeMut::new_int(3));
+            dict_mut.insert((&b"c"[..]).into(), BencodeMut::new_int(4));
+        }
+
+        assert_eq!(&b"d1:ai2e1:bli1eee"[..], &template.encode()[..]);
+        assert_eq!(&b"d1:ai2e1:bli1ei3ee1:ci4ee"[..], &cloned.encode()[..]);
+        assert_eq!(template.encode(), template.clone().encode());
+    }
+
+    #[test]
+    fn positive_encode_sorted_by_reversed() {
+        let mut bencode_dict = BencodeMut::new_dict();
+        {
+            let dict_mut = bencode_dict.dict_mut().unwrap();
+            dict_mut.insert((&b"a"[..]).into(), BencodeMut::new_int(1));
+            dict_mut.insert((&b"b"[..]).into(), BencodeMut::new_int(2));
+        }
+
+        assert_eq!(&b"d1:ai1e1:bi2ee"[..], &bencode_dict.encode_sorted_by(|a, b| a.cmp(b))[..]);
+        assert_eq!(&b"d1:bi2e1:ai1ee"[..], &bencode_dict.encode_sorted_by(|a, b| b.cmp(a))[..]);
+    }
+
+    #[test]
+    fn positive_encode_with_remove_and_replace() {
+        let mut bencode_dict = BencodeMut::new_dict();
+        {
+            let mut info_dict = BencodeMut::new_dict();
+            info_dict.dict_mut().unwrap().insert((&b"comment"[..]).into(), BencodeMut::new_int(1));
+
+            let dict_mut = bencode_dict.dict_mut().unwrap();
+            dict_mut.insert((&b"comment"[..]).into(), BencodeMut::new_bytes((&b"secret"[..]).into()));
+            dict_mut.insert((&b"created by"[..]).into(), BencodeMut::new_bytes((&b"me"[..]).into()));
+            dict_mut.insert((&b"info"[..]).into(), info_dict);
+        }
+
+        let bytes = bencode_dict.encode_with(|path, _| {
+            match path {
+                [b"comment"]    => EncodeAction::Remove,
+                [b"created by"] => EncodeAction::Replace(BencodeMut::new_bytes((&b"anon"[..]).into())),
+                _               => EncodeAction::Keep
+            }
+        });
+
+        assert_eq!(&b"d10:created by4:anon4:infod7:commenti1eee"[..], &bytes[..]);
+        assert!(bencode_dict.dict().unwrap().lookup(b"comment").is_some());
+    }
+
+    #[test]
+    fn positive_encode_with_keep_matches_encode() {
+        let bencode = BencodeMut::list_from_iter(vec![BencodeMut::new_int(5), BencodeMut::new_bytes((&b"spam"[..]).into())]);
+
+        assert_eq!(bencode.encode(), bencode.encode_with(|_, _| EncodeAction::Keep));
+    }
+
+    #[test]
+    fn positive_encode_validated() {
+        let bencode = BencodeMut::dict_from_iter(vec![("b", BencodeMut::new_int(-10)),
+                                                      ("a", BencodeMut::list_from_iter(vec![BencodeMut::new_int(0)]))]);
+
+        assert_eq!(bencode.encode(), bencode.encode_validated().unwrap());
+    }
+
+    #[test]
+    fn positive_into_components() {
+        assert_eq!(Some(-560), BencodeMut::new_int(-560).into_int());
+        assert_eq!(Some(b"asdasd".to_vec()), BencodeMut::new_bytes((&b"asdasd"[..]).into()).into_bytes());
+
+        let mut bencode_list = BencodeMut::new_list();
+        bencode_list.list_mut().unwrap().push(BencodeMut::new_int(56));
+        assert_eq!(vec![BencodeMut::new_int(56)], bencode_list.into_list().unwrap());
+
+        let mut bencode_dict = BencodeMut::new_dict();
+        bencode_dict.dict_mut().unwrap().insert((&b"key"[..]).into(), BencodeMut::new_int(1));
+        let mut dict = bencode_dict.into_dict().unwrap();
+        assert_eq!(Some(BencodeMut::new_int(1)), dict.remove(&b"key"[..]));
+    }
+
+    #[test]
+    fn negative_into_wrong_component() {
+        assert!(BencodeMut::new_int(-560).into_bytes().is_none());
+        assert!(BencodeMut::new_list().into_dict().is_none());
+        assert!(BencodeMut::new_dict().into_list().is_none());
+        assert!(BencodeMut::new_bytes((&b""[..]).into()).into_int().is_none());
+    }
+
+    #[test]
+    fn positive_list_from_iter_encode() {
+        let bencode_list: BencodeMut = (0..3).map(BencodeMut::new_int).collect();
+
+        let list_bytes = b"li0ei1ei2ee";
+        assert_eq!(&list_bytes[..], &bencode_list.encode()[..]);
+    }
+
+    #[test]
+    fn positive_dict_from_iter_encode() {
+        let entries = vec![(b"b".to_vec(), BencodeMut::new_int(1)), (b"a".to_vec(), BencodeMut::new_int(0))];
+        let bencode_dict: BencodeMut = entries.into_iter().collect();
+
+        let dict_bytes = b"d1:ai0e1:bi1ee";
+        assert_eq!(&dict_bytes[..], &bencode_dict.encode()[..]);
+    }
+
+    #[test]
+    fn positive_borrowing_edit_single_value() {
+        let bytes = b"d8:announce9:tracker_a4:infod6:pieces4:abcdee";
+        let bencode = BencodeRef::decode(&bytes[..], BDecodeOpt::default()).unwrap();
+
+        let mut bencode_mut = BencodeMut::borrowing(&bencode);
+        bencode_mut.dict_mut().unwrap().insert((&b"announce"[..]).into(), BencodeMut::new_bytes((&b"tracker_b"[..]).into()));
+
+        let pieces = bencode_mut.dict().unwrap().lookup(b"info").unwrap().dict().unwrap().lookup(b"pieces").unwrap();
+        match pieces.inner {
+            InnerBencodeMut::Bytes(Cow::Borrowed(n)) => assert_eq!(bytes[39..43].as_ptr(), n.as_ptr()),
+            _ => panic!("Expected Borrowed Bytes")
+        }
+        assert_eq!(&b"d8:announce9:tracker_b4:infod6:pieces4:abcdee"[..], &bencode_mut.encode()[..]);
+    }
+
+    #[test]
+    fn positive_validate_nested() {
+        let mut bencode = BencodeMut::new_int(0);
+        for _ in 0..(BDecodeOpt::default().max_recursion() - 1) {
+            bencode = BencodeMut::list_from_iter(vec![bencode]);
+        }
+
+        bencode.validate().unwrap();
+        BencodeRef::decode(&bencode.encode(), BDecodeOpt::default()).unwrap();
+    }
+
+    #[test]
+    fn negative_validate_nested_too_deep() {
+        let mut bencode = BencodeMut::new_int(0);
+        for _ in 0..BDecodeOpt::default().max_recursion() {
+            bencode = BencodeMut::list_from_iter(vec![bencode]);
+        }
+
+        match *bencode.validate().unwrap_err().kind() {
+            BencodeEncodeErrorKind::InvalidValue{ .. } => (),
+            _ => panic!("Expected A InvalidValue Error")
+        }
+        BencodeRef::decode(&bencode.encode(), BDecodeOpt::default()).unwrap_err();
+    }
+
+    #[test]
+    fn negative_validate_with_check_path() {
+        let bencode = BencodeMut::dict_from_iter(vec![
+            ("info", BencodeMut::dict_from_iter(vec![("name", BencodeMut::new_int(5))]))
+        ]);
+
+        let error = bencode.validate_with(|path, value| {
+            if path == [&b"info"[..], &b"name"[..]] && value.bytes().is_none() {
+                Err("Name Is Not Bytes".to_owned())
+            } else {
+                Ok(())
+            }
+        }).unwrap_err();
+        match *error.kind() {
+            BencodeEncodeErrorKind::InvalidValue{ ref path, .. } => assert_eq!(&vec![b"info".to_vec(), b"name".to_vec()], path),
+            _ => panic!("Expected A InvalidValue Error")
+        }
+    }
+
+    #[test]
+    fn positive_dict_from_iter_static_keys_borrowed() {
+        let bencode_dict = BencodeMut::dict_from_iter(vec![("b", BencodeMut::new_int(2)), ("a", BencodeMut::new_int(1))]);
+
+        for (key, _) in bencode_dict.dict().unwrap().to_list() {
+            match *key {
+                Cow::Borrowed(_) => (),
+                Cow::Owned(_)    => panic!("Expected A Borrowed Key")
+            }
+        }
+        assert_eq!(&b"d1:ai1e1:bi2ee"[..], &bencode_dict.encode()[..]);
+    }
+
+    #[test]
+    fn positive_into_dict_iter_moves_values() {
+        let entries = vec![(b"b".to_vec(), BencodeMut::new_int(1)), (b"a".to_vec(), BencodeMut::new_int(0))];
+        let bencode_dict = BencodeMut::dict_from_iter(entries);
+
+        let mut values = Vec::new();
+        for (key, value) in bencode_dict.into_dict().unwrap() {
+            values.push((key.into_owned(), value.into_int().unwrap()));
+        }
+
+        assert_eq!(vec![(b"a".to_vec(), 0), (b"b".to_vec(), 1)], values);
+    }
+
+    #[test]
+    fn positive_encode_into_slice() {
+        let mut bencode_list = BencodeMut::new_list();
+        bencode_list.list_mut().unwrap().push(BencodeMut::new_int(56));
+
+        let mut buffer = [0u8; 10];
+        let written = bencode_list.encode_into_slice(&mut buffer).unwrap();
+
+        assert_eq!(&b"li56ee"[..], &buffer[..written]);
+    }
+
+    #[test]
+    fn positive_encode_into_slice_matches_encode() {
+        let bencode = BencodeMut::dict_from_iter(vec![("b", BencodeMut::list_from_iter(vec![BencodeMut::new_int(::std::i64::MIN)])),
+                                                      ("a", BencodeMut::new_bytes((&b"asdasd"[..]).into()))]);
+
+        let mut buffer = vec![0u8; bencode.encoded_len()];
+        let written = bencode.encode_into_slice(&mut buffer).unwrap();
+
+        assert_eq!(bencode.encode(), &buffer[..written]);
+    }
+
+    #[test]
+    fn negative_encode_into_slice_too_small() {
+        let bencode_bytes = BencodeMut::new_bytes((&b"asdasd"[..]).into());
+
+        let mut buffer = [0u8; 7];
+        bencode_bytes.encode_into_slice(&mut buffer).unwrap_err();
+        assert_eq!([0u8; 7], buffer);
+    }
+
+    #[test]
+    fn positive_new_bytes_owned_no_copy() {
+        let bytes = vec![0u8; 1024];
+        let bytes_ptr = bytes.as_ptr();
+
+        let bencode_bytes = BencodeMut::new_bytes_owned(bytes);
+        let owned_bytes = bencode_bytes.into_bytes().unwrap();
+        assert_eq!(bytes_ptr, owned_bytes.as_ptr());
+    }
+}
@@ -1,57 +1,290 @@
+use std::cmp::Ordering;
+
 use access::bencode::{BencodeRefKind, BRefAccess};
 use access::dict::BDictAccess;
 use access::list::BListAccess;
+use mutable::bencode_mut::BencodeMut;
 
-use std::iter::Extend;
+/// Destination for encoded bencode bytes.
+pub trait EncodeSink {
+    /// Append the given bytes to the sink.
+    fn put(&mut self, bytes: &[u8]);
+}
 
-pub fn encode<T>(val: T, bytes: &mut Vec<u8>)
-    where T: BRefAccess, T::BKey: AsRef<[u8]> {
+impl EncodeSink for Vec<u8> {
+    fn put(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes);
+    }
+}
+
+/// Sink that writes into a fixed size buffer, which must be large enough for the output.
+///
+/// Writing past the end of the buffer panics, so callers must check the buffer against
+/// `encoded_len` before encoding, as `BencodeMut::encode_into_slice` does.
+pub struct SliceSink<'a> {
+    buffer: &'a mut [u8],
+    pos:    usize
+}
+
+impl<'a> SliceSink<'a> {
+    pub fn new(buffer: &'a mut [u8]) -> SliceSink<'a> {
+        SliceSink{ buffer: buffer, pos: 0 }
+    }
+
+    /// Number of bytes written to the buffer.
+    pub fn written(&self) -> usize {
+        self.pos
+    }
+}
+
+impl<'a> EncodeSink for SliceSink<'a> {
+    /// Panics if the bytes do not fit in the remainder of the buffer.
+    fn put(&mut self, bytes: &[u8]) {
+        let end_pos = self.pos + bytes.len();
+
+        self.buffer[self.pos..end_pos].copy_from_slice(bytes);
+        self.pos = end_pos;
+    }
+}
+
+pub fn encode<T, S>(val: T, bytes: &mut S)
+    where T: BRefAccess, T::BKey: AsRef<[u8]>, S: EncodeSink {
+    encode_sorted_by(val, bytes, &|a, b| a.cmp(b))
+}
+
+/// Encode any bencode, such as a `BencodeRef`, into a new buffer.
+///
+/// Dictionary keys are always written in sorted order, so the output is canonical even if the
+/// bencode was decoded from bytes with unsorted keys.
+pub fn bencode_encode<B>(bencode: &B) -> Vec<u8>
+    where B: BRefAccess, B::BKey: AsRef<[u8]> {
+    let mut buffer = Vec::with_capacity(encoded_len(bencode));
+
+    encode(bencode, &mut buffer);
+
+    buffer
+}
+
+/// Encode the given value, ordering dictionary keys with the given comparator instead of by raw bytes.
+pub fn encode_sorted_by<T, S>(val: T, bytes: &mut S, cmp: &Fn(&[u8], &[u8]) -> Ordering)
+    where T: BRefAccess, T::BKey: AsRef<[u8]>, S: EncodeSink {
     match val.kind() {
         BencodeRefKind::Int(n)  => encode_int(n, bytes),
         BencodeRefKind::Bytes(n) => encode_bytes(&n, bytes),
-        BencodeRefKind::List(n) => encode_list(n, bytes),
-        BencodeRefKind::Dict(n) => encode_dict(n, bytes),
+        BencodeRefKind::List(n) => encode_list(n, bytes, cmp),
+        BencodeRefKind::Dict(n) => encode_dict(n, bytes, cmp),
     }
 }
 
-fn encode_int(val: i64, bytes: &mut Vec<u8>) {
-    bytes.push(::INT_START);
-
-    bytes.extend(val.to_string().into_bytes());
+/// Action to take for a value when encoding with `BencodeMut::encode_with`.
+pub enum EncodeAction<'a> {
+    /// Encode the value as is.
+    Keep,
+    /// Leave the value out of the output, along with its key if it is within a dictionary.
+    Remove,
+    /// Encode the given value in place of the original, without visiting it.
+    Replace(BencodeMut<'a>)
+}
 
-    bytes.push(::BEN_END);
+/// Encode the given value, asking the visitor what to do with each value before it is encoded.
+///
+/// The visitor receives the dictionary keys leading to the value, list elements do not add to the path.
+pub fn encode_with<'b, V, S, F>(val: &V, bytes: &mut S, path: &[&[u8]], visit: &mut F)
+    where V: BRefAccess<BType=V>, V::BKey: AsRef<[u8]>, S: EncodeSink, F: FnMut(&[&[u8]], &V) -> EncodeAction<'b> {
+    match visit(path, val) {
+        EncodeAction::Keep       => encode_visited(val, bytes, path, visit),
+        EncodeAction::Remove     => (),
+        EncodeAction::Replace(n) => encode(&n, bytes)
+    }
 }
 
-fn encode_bytes(list: &[u8], bytes: &mut Vec<u8>) {
-    bytes.extend(list.len().to_string().into_bytes());
+/// Encode a value that has already been visited, visiting each of its children.
+fn encode_visited<'b, V, S, F>(val: &V, bytes: &mut S, path: &[&[u8]], visit: &mut F)
+    where V: BRefAccess<BType=V>, V::BKey: AsRef<[u8]>, S: EncodeSink, F: FnMut(&[&[u8]], &V) -> EncodeAction<'b> {
+    match val.kind() {
+        BencodeRefKind::Int(n)   => encode_int(n, bytes),
+        BencodeRefKind::Bytes(n) => encode_bytes(&n, bytes),
+        BencodeRefKind::List(n)  => {
+            bytes.put(&[::LIST_START]);
+            for i in n {
+                encode_with(i, bytes, path, visit);
+            }
+            bytes.put(&[::BEN_END]);
+        },
+        BencodeRefKind::Dict(n)  => {
+            let mut sort_dict = n.to_list();
+            (&mut sort_dict[..]).sort_by(|&(a, _), &(b, _)| a.as_ref().cmp(b.as_ref()));
 
-    bytes.push(::BYTE_LEN_END);
+            bytes.put(&[::DICT_START]);
+            for &(key, value) in sort_dict.iter() {
+                let mut value_path = path.to_vec();
+                value_path.push(key.as_ref());
 
-    bytes.extend(list.iter().map(|n| *n));
+                // Visit before writing the key, so that a removed value takes its key with it
+                match visit(&value_path, value) {
+                    EncodeAction::Keep       => {
+                        encode_bytes(key.as_ref(), bytes);
+                        encode_visited(value, bytes, &value_path, visit);
+                    },
+                    EncodeAction::Remove     => (),
+                    EncodeAction::Replace(n) => {
+                        encode_bytes(key.as_ref(), bytes);
+                        encode(&n, bytes);
+                    }
+                }
+            }
+            bytes.put(&[::BEN_END]);
+        }
+    }
 }
 
-fn encode_list<T>(list: &BListAccess<T>, bytes: &mut Vec<u8>)
+/// Compute the exact number of bytes that `encode` would write for the given value.
+pub fn encoded_len<T>(val: T) -> usize
     where T: BRefAccess, T::BKey: AsRef<[u8]> {
-    bytes.push(::LIST_START);
+    match val.kind() {
+        BencodeRefKind::Int(n)   => int_len(n),
+        BencodeRefKind::Bytes(n) => bytes_len(n),
+        BencodeRefKind::List(n)  => {
+            // Start And End Markers Plus Each Element
+            2 + n.into_iter().map(|i| encoded_len(i)).sum::<usize>()
+        },
+        BencodeRefKind::Dict(n)  => {
+            // Start And End Markers Plus Each (Key, Value) Pair
+            2 + n.to_list().iter().map(|&(key, value)| bytes_len(key.as_ref()) + encoded_len(value)).sum::<usize>()
+        }
+    }
+}
+
+fn int_len(val: i64) -> usize {
+    // Start And End Markers Plus The Decimal Representation
+    2 + decimal_len(val)
+}
+
+fn bytes_len(list: &[u8]) -> usize {
+    // Length Prefix Plus The Separator Plus The Bytes
+    decimal_len(list.len() as i64) + 1 + list.len()
+}
+
+fn decimal_len(val: i64) -> usize {
+    let mut len = if val < 0 { 2 } else { 1 };
+    let mut remaining = val / 10;
+
+    while remaining != 0 {
+        len += 1;
+        remaining /= 10;
+    }
+
+    len
+}
+
+/// Maximum number of bytes in the decimal representation of an `i64`, including the sign.
+const MAX_DECIMAL_LEN: usize = 20;
+
+/// Write the decimal representation of the value into the end of the buffer, returning the written bytes.
+fn format_decimal(val: i64, buffer: &mut [u8; MAX_DECIMAL_LEN]) -> &[u8] {
+    let mut start = MAX_DECIMAL_LEN;
+    let mut remaining = val;
+
+    loop {
+        // Taking the absolute value of each digit, rather than the value, handles i64::MIN
+        start -= 1;
+        buffer[start] = b'0' + (remaining % 10).abs() as u8;
+        remaining /= 10;
+
+        if remaining == 0 {
+            break
+        }
+    }
+    if val < 0 {
+        start -= 1;
+        buffer[start] = b'-';
+    }
+
+    &buffer[start..]
+}
+
+pub fn encode_int<S>(val: i64, bytes: &mut S)
+    where S: EncodeSink {
+    let mut buffer = [0u8; MAX_DECIMAL_LEN];
+
+    bytes.put(&[::INT_START]);
+
+    bytes.put(format_decimal(val, &mut buffer));
+
+    bytes.put(&[::BEN_END]);
+}
+
+pub fn encode_bytes<S>(list: &[u8], bytes: &mut S)
+    where S: EncodeSink {
+    let mut buffer = [0u8; MAX_DECIMAL_LEN];
+
+    bytes.put(format_decimal(list.len() as i64, &mut buffer));
+
+    bytes.put(&[::BYTE_LEN_END]);
+
+    bytes.put(list);
+}
+
+fn encode_list<T, S>(list: &BListAccess<T>, bytes: &mut S, cmp: &Fn(&[u8], &[u8]) -> Ordering)
+    where T: BRefAccess, T::BKey: AsRef<[u8]>, S: EncodeSink {
+    bytes.put(&[::LIST_START]);
 
     for i in list {
-        encode(i, bytes);
+        encode_sorted_by(i, bytes, cmp);
     }
 
-    bytes.push(::BEN_END);
+    bytes.put(&[::BEN_END]);
 }
 
-fn encode_dict<'a, K, V>(dict: &BDictAccess<K, V>, bytes: &mut Vec<u8>)
-    where K: AsRef<[u8]>, V: BRefAccess, V::BKey: AsRef<[u8]> {
+fn encode_dict<'a, K, V, S>(dict: &BDictAccess<K, V>, bytes: &mut S, cmp: &Fn(&[u8], &[u8]) -> Ordering)
+    where K: AsRef<[u8]>, V: BRefAccess, V::BKey: AsRef<[u8]>, S: EncodeSink {
     // Need To Sort The Keys In The Map Before Encoding
     let mut sort_dict = dict.to_list();
-    sort_dict.sort_by(|&(a, _), &(b, _)| a.as_ref().cmp(b.as_ref()));
+    (&mut sort_dict[..]).sort_by(|&(a, _), &(b, _)| cmp(a.as_ref(), b.as_ref()));
 
-    bytes.push(::DICT_START);
+    bytes.put(&[::DICT_START]);
     // Iterate And Dictionary Encode The (String, Bencode) Pairs
     for &(ref key, ref value) in sort_dict.iter() {
         encode_bytes(key.as_ref(), bytes);
-        encode(*value, bytes);
+        encode_sorted_by(*value, bytes, cmp);
+    }
+    bytes.put(&[::BEN_END]);
+}
+
+#[cfg(test)]
+mod tests {
+    use mutable::bencode_mut::BencodeMut;
+    use reference::bencode_ref::BencodeRef;
+    use reference::decode_opt::BDecodeOpt;
+
+    #[test]
+    fn positive_bencode_encode_sorts_ref_keys() {
+        let bencode = BencodeRef::decode(b"d1:bi1e1:ai0ee", BDecodeOpt::default()).unwrap();
+
+        assert_eq!(&b"d1:ai0e1:bi1ee"[..], &super::bencode_encode(&bencode)[..]);
+    }
+
+    #[test]
+    fn positive_encoded_len_edge_integers() {
+        for &value in [0, 9, 10, -1, -10, ::std::i64::MAX, ::std::i64::MIN].iter() {
+            let mut bytes = Vec::new();
+            super::encode(&BencodeMut::new_int(value), &mut bytes);
+
+            assert_eq!(bytes.len(), super::encoded_len(&BencodeMut::new_int(value)));
+        }
+    }
+
+    #[test]
+    fn positive_encode_int_canonical() {
+        let cases: &[(i64, &[u8])] = &[(0, b"i0e"), (-1, b"i-1e"), (10, b"i10e"),
+                                       (::std::i64::MAX, b"i9223372036854775807e"),
+                                       (::std::i64::MIN, b"i-9223372036854775808e")];
+
+        for &(value, expected) in cases.iter() {
+            let mut bytes = Vec::new();
+            super::encode(&BencodeMut::new_int(value), &mut bytes);
+
+            assert_eq!(expected, &bytes[..]);
+        }
     }
-    bytes.push(::BEN_END);
 }
@@ -1,2 +1,2 @@
 pub mod bencode_mut;
-mod encode;
\ No newline at end of file
+pub mod encode;
\ No newline at end of file
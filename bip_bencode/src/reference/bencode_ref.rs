@@ -1,5 +1,8 @@
 use access::bencode::BRefAccessExt;
 use std::collections::BTreeMap;
+use std::fmt::{self, Debug, Formatter};
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
 use std::str;
 
 use access::bencode::{BRefAccess, BencodeRefKind};
@@ -10,7 +13,7 @@ use access::list::BListAccess;
 use error::{BencodeParseResult, BencodeParseError, BencodeParseErrorKind};
 
 /// Bencode object that holds references to the underlying data.
-#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+#[derive(Debug, Clone)]
 pub enum InnerBencodeRef<'a> {
     /// Bencode Integer.
     Int(i64, &'a [u8]),
@@ -22,16 +25,88 @@ pub enum InnerBencodeRef<'a> {
     Dict(BTreeMap<&'a [u8], BencodeRef<'a>>, &'a [u8]),
 }
 
-impl<'a> Into<BencodeRef<'a>> for InnerBencodeRef<'a> {
+// Equality and hashing are structural, the source buffer is ignored so that two
+// dictionaries with the same entries compare equal regardless of source ordering.
+impl<'a> PartialEq for InnerBencodeRef<'a> {
+    fn eq(&self, other: &InnerBencodeRef<'a>) -> bool {
+        match (self, other) {
+            (&InnerBencodeRef::Int(a, _), &InnerBencodeRef::Int(b, _))           => a == b,
+            (&InnerBencodeRef::Bytes(a, _), &InnerBencodeRef::Bytes(b, _))       => a == b,
+            (&InnerBencodeRef::List(ref a, _), &InnerBencodeRef::List(ref b, _)) => a == b,
+            (&InnerBencodeRef::Dict(ref a, _), &InnerBencodeRef::Dict(ref b, _)) => a == b,
+            _ => false
+        }
+    }
+}
+
+impl<'a> Eq for InnerBencodeRef<'a> {}
+
+impl<'a> Hash for InnerBencodeRef<'a> {
+    fn hash<H>(&self, state: &mut H) where H: Hasher {
+        match *self {
+            InnerBencodeRef::Int(n, _) => {
+                0u8.hash(state);
+                n.hash(state);
+            },
+            InnerBencodeRef::Bytes(n, _) => {
+                1u8.hash(state);
+                n.hash(state);
+            },
+            InnerBencodeRef::List(ref n, _) => {
+                2u8.hash(state);
+                n.hash(state);
+            },
+            // BTreeMap yields entries in sorted key order
+            InnerBencodeRef::Dict(ref n, _) => {
+                3u8.hash(state);
+                n.hash(state);
+            }
+        }
+    }
+}
+
+impl<'a> Into<BencodeRef<'a>> for (InnerBencodeRef<'a>, &'a [u8]) {
     fn into(self) -> BencodeRef<'a> {
-        BencodeRef{ inner: self }
+        BencodeRef{ inner: self.0, source: self.1 }
     }
 }
 
 /// `BencodeRef` object that stores references to some buffer.
-#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+///
+/// Byte strings and dictionary keys are borrowed from the decoded buffer and are never
+/// copied, so decoding a large byte string does not duplicate it in memory.
+///
+/// Equality and hashing are structural, meaning the source bytes of a node are not
+/// considered, and dictionaries are compared and hashed in sorted key order. Both
+/// operations walk the entire tree, so hashing a large dictionary (such as a metainfo
+/// file with a large pieces field) is proportional to the size of that dictionary.
+#[derive(Clone)]
 pub struct BencodeRef<'a> {
-    inner: InnerBencodeRef<'a>
+    inner:  InnerBencodeRef<'a>,
+    // Entire input that the tree was decoded from, shared by every node.
+    source: &'a [u8]
+}
+
+// The source buffer is left out so that comparisons remain structural and debug
+// output does not repeat the entire input for every node.
+impl<'a> PartialEq for BencodeRef<'a> {
+    fn eq(&self, other: &BencodeRef<'a>) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<'a> Eq for BencodeRef<'a> {}
+
+impl<'a> Hash for BencodeRef<'a> {
+    fn hash<H>(&self, state: &mut H) where H: Hasher {
+        self.inner.hash(state);
+    }
+}
+
+impl<'a> Debug for BencodeRef<'a> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("BencodeRef").field("inner", &self.inner).finish()
+    }
 }
 
 impl<'a> BencodeRef<'a> {
@@ -47,6 +122,71 @@ impl<'a> BencodeRef<'a> {
         Ok(bencode)
     }
 
+    /// Decode the given bytes, which must be a dictionary, into its entries using the given decode options.
+    ///
+    /// Returns an `InvalidByte` error at position 0, without decoding, if the bytes do not start a dictionary.
+    pub fn decode_dict(bytes: &'a [u8], opts: BDecodeOpt) -> BencodeParseResult<BTreeMap<&'a [u8], BencodeRef<'a>>> {
+        try!(check_root_byte(bytes, ::DICT_START));
+
+        match try!(BencodeRef::decode(bytes, opts)).inner {
+            InnerBencodeRef::Dict(n, _) => Ok(n),
+            _ => Err(BencodeParseError::from_kind(BencodeParseErrorKind::InvalidByte{ pos: 0 }))
+        }
+    }
+
+    /// Decode the given bytes, which must be a list, into its elements using the given decode options.
+    ///
+    /// Returns an `InvalidByte` error at position 0, without decoding, if the bytes do not start a list.
+    pub fn decode_list(bytes: &'a [u8], opts: BDecodeOpt) -> BencodeParseResult<Vec<BencodeRef<'a>>> {
+        try!(check_root_byte(bytes, ::LIST_START));
+
+        match try!(BencodeRef::decode(bytes, opts)).inner {
+            InnerBencodeRef::List(n, _) => Ok(n),
+            _ => Err(BencodeParseError::from_kind(BencodeParseErrorKind::InvalidByte{ pos: 0 }))
+        }
+    }
+
+    /// Decode the given bytes into a list of back to back `BencodeRef` objects using the given decode options.
+    ///
+    /// Decoding continues until all bytes are consumed, so any trailing partial object results in an error.
+    /// The full decode option is not applicable here and is ignored.
+    pub fn decode_all(bytes: &'a [u8], opts: BDecodeOpt) -> BencodeParseResult<Vec<BencodeRef<'a>>> {
+        let mut bencodes = Vec::new();
+        let mut pos = 0;
+
+        while pos != bytes.len() {
+            let (bencode, next_pos) = try!(decode::decode(bytes, pos, opts, 0));
+
+            bencodes.push(bencode);
+            pos = next_pos;
+        }
+
+        Ok(bencodes)
+    }
+
+    /// Dictionary entries in the order that their keys first appear in the source bytes.
+    ///
+    /// Comparing this against `entries_sorted` is one way to detect non canonical bencode.
+    /// Returns None if the bencode is not a dictionary.
+    pub fn entries(&self) -> Option<Vec<(&'a [u8], &BencodeRef<'a>)>> {
+        self.entries_sorted().map(|mut entries| {
+            // Keys are slices of the source bytes, so their addresses follow source order
+            (&mut entries[..]).sort_by_key(|&(key, _)| key.as_ptr() as usize);
+
+            entries
+        })
+    }
+
+    /// Dictionary entries in sorted key order, which is the canonical bencode order.
+    ///
+    /// Returns None if the bencode is not a dictionary.
+    pub fn entries_sorted(&self) -> Option<Vec<(&'a [u8], &BencodeRef<'a>)>> {
+        match self.inner {
+            InnerBencodeRef::Dict(ref n, _) => Some(n.iter().map(|(&key, value)| (key, value)).collect()),
+            _ => None
+        }
+    }
+
     /// Get a byte slice of the current bencode byte representation.
     pub fn buffer(&self) -> &'a [u8] {
         match self.inner {
@@ -56,6 +196,31 @@ impl<'a> BencodeRef<'a> {
             InnerBencodeRef::Dict(_, buffer)  => buffer
         }
     }
+
+    /// Get a byte slice of the entire input that this bencode was decoded from.
+    ///
+    /// For nested bencode, this is the input given to the decode call, not just the nested bytes.
+    pub fn source(&self) -> &'a [u8] {
+        self.source
+    }
+
+    /// Range of the current bencode byte representation within `BencodeRef::source`.
+    ///
+    /// Slicing the source with this range yields the same bytes as `BencodeRef::buffer`.
+    pub fn source_span(&self) -> Range<usize> {
+        let start = self.buffer().as_ptr() as usize - self.source.as_ptr() as usize;
+
+        start..(start + self.buffer().len())
+    }
+}
+
+/// Check that the given bytes start with the given byte.
+fn check_root_byte(bytes: &[u8], expected: u8) -> BencodeParseResult<()> {
+    match bytes.first() {
+        Some(&n) if n == expected => Ok(()),
+        Some(_)                   => Err(BencodeParseError::from_kind(BencodeParseErrorKind::InvalidByte{ pos: 0 })),
+        None                      => Err(BencodeParseError::from_kind(BencodeParseErrorKind::BytesEmpty{ pos: 0 }))
+    }
 }
 
 impl<'a> BRefAccess for BencodeRef<'a> {
@@ -124,12 +289,109 @@ impl<'a> BRefAccessExt<'a> for BencodeRef<'a> {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashSet;
+    use std::collections::hash_map::DefaultHasher;
     use std::default::Default;
+    use std::hash::{Hash, Hasher};
 
     use access::bencode::BRefAccess;
+    use access::dict::BDictAccess;
     use reference::bencode_ref::BencodeRef;
     use reference::decode_opt::BDecodeOpt;
 
+    #[test]
+    fn positive_bytes_borrowed_from_input() {
+        let bencode_bytes = b"d6:pieces10:0123456789e";
+        let bencode = BencodeRef::decode(&bencode_bytes[..], BDecodeOpt::default()).unwrap();
+
+        let (key, value) = bencode.entries().unwrap()[0];
+        let pieces = value.bytes().unwrap();
+
+        let input_range = (bencode_bytes.as_ptr() as usize)..(bencode_bytes.as_ptr() as usize + bencode_bytes.len());
+        assert!(input_range.contains(&(key.as_ptr() as usize)));
+        assert!(input_range.contains(&(pieces.as_ptr() as usize)));
+        assert!(input_range.contains(&(pieces.as_ptr() as usize + pieces.len() - 1)));
+    }
+
+    #[test]
+    fn positive_source_span_nested() {
+        let bencode_bytes = b"d1:ai5e1:bli1e4:spamee";
+        let bencode = BencodeRef::decode(&bencode_bytes[..], BDecodeOpt::default()).unwrap();
+
+        let list = bencode.dict().unwrap().lookup(b"b").unwrap();
+        let spam = &list.list().unwrap().get(1).unwrap();
+
+        assert_eq!(&bencode_bytes[..], bencode.source());
+        assert_eq!(&bencode_bytes[..], spam.source());
+        assert_eq!(0..bencode_bytes.len(), bencode.source_span());
+        assert_eq!(&b"li1e4:spame"[..], &list.source()[list.source_span()]);
+        assert_eq!(spam.buffer(), &spam.source()[spam.source_span()]);
+    }
+
+    #[test]
+    fn positive_decode_dict() {
+        let dict = BencodeRef::decode_dict(b"d1:ai5ee", BDecodeOpt::default()).unwrap();
+
+        assert_eq!(Some(5), dict.lookup(b"a").and_then(|a| a.int()));
+    }
+
+    #[test]
+    fn negative_decode_dict_not_dict() {
+        BencodeRef::decode_dict(b"li5ee", BDecodeOpt::default()).unwrap_err();
+        BencodeRef::decode_dict(b"", BDecodeOpt::default()).unwrap_err();
+    }
+
+    #[test]
+    fn positive_decode_list() {
+        let list = BencodeRef::decode_list(b"li5e4:spame", BDecodeOpt::default()).unwrap();
+
+        assert_eq!(Some(&b"spam"[..]), list[1].bytes());
+    }
+
+    #[test]
+    fn negative_decode_list_not_list() {
+        BencodeRef::decode_list(b"d1:ai5ee", BDecodeOpt::default()).unwrap_err();
+    }
+
+    #[test]
+    fn positive_decode_all() {
+        let bencode_bytes = b"i5e4:spamli1eed1:ai2ee";
+        let bencodes = BencodeRef::decode_all(&bencode_bytes[..], BDecodeOpt::default()).unwrap();
+
+        let buffers: Vec<&[u8]> = bencodes.iter().map(|bencode| bencode.buffer()).collect();
+        assert_eq!(vec![&b"i5e"[..], b"4:spam", b"li1ee", b"d1:ai2ee"], buffers);
+    }
+
+    #[test]
+    fn positive_decode_all_empty() {
+        assert!(BencodeRef::decode_all(&b""[..], BDecodeOpt::default()).unwrap().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_decode_all_trailing_partial() {
+        BencodeRef::decode_all(&b"i5eli1e"[..], BDecodeOpt::default()).unwrap();
+    }
+
+    #[test]
+    fn positive_entries_source_order() {
+        let bencode = BencodeRef::decode(&b"d1:bi1e1:ci2e1:ai3ee"[..], BDecodeOpt::default()).unwrap();
+
+        let keys: Vec<&[u8]> = bencode.entries().unwrap().into_iter().map(|(key, _)| key).collect();
+        assert_eq!(vec![&b"b"[..], b"c", b"a"], keys);
+
+        let sorted_keys: Vec<&[u8]> = bencode.entries_sorted().unwrap().into_iter().map(|(key, _)| key).collect();
+        assert_eq!(vec![&b"a"[..], b"b", b"c"], sorted_keys);
+    }
+
+    #[test]
+    fn negative_entries_not_dict() {
+        let bencode = BencodeRef::decode(&b"li1ee"[..], BDecodeOpt::default()).unwrap();
+
+        assert!(bencode.entries().is_none());
+        assert!(bencode.entries_sorted().is_none());
+    }
+
     #[test]
     fn positive_int_buffer() {
         let int_bytes = b"i-500e";
@@ -257,4 +519,48 @@ mod tests {
         let dict_bytes = b"d3:asd3:asde";
         assert_eq!(dict_bytes, bencode_dict.buffer());
     }
-}
\ No newline at end of file
+
+    fn hash_of<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    #[test]
+    fn positive_dict_hash_ignores_source_ordering() {
+        let sorted_bytes = b"d1:ai1e1:bi2ee";
+        let unsorted_bytes = b"d1:bi2e1:ai1ee";
+
+        let sorted = BencodeRef::decode(&sorted_bytes[..], BDecodeOpt::default()).unwrap();
+        let unsorted = BencodeRef::decode(&unsorted_bytes[..], BDecodeOpt::default()).unwrap();
+
+        assert_eq!(sorted, unsorted);
+        assert_eq!(hash_of(&sorted), hash_of(&unsorted));
+    }
+
+    #[test]
+    fn positive_hash_set_dedupes_equal_bencode() {
+        let first_bytes = b"l3:asdi5ee";
+        let second_bytes = b"l3:asdi5ee";
+        let third_bytes = b"l3:asdi6ee";
+
+        let mut set = HashSet::new();
+        set.insert(BencodeRef::decode(&first_bytes[..], BDecodeOpt::default()).unwrap());
+        set.insert(BencodeRef::decode(&second_bytes[..], BDecodeOpt::default()).unwrap());
+        set.insert(BencodeRef::decode(&third_bytes[..], BDecodeOpt::default()).unwrap());
+
+        assert_eq!(2, set.len());
+    }
+
+    #[test]
+    fn negative_int_bytes_not_equal() {
+        let int_bytes = b"i5e";
+        let bytes_bytes = b"1:5";
+
+        let int = BencodeRef::decode(&int_bytes[..], BDecodeOpt::default()).unwrap();
+        let bytes = BencodeRef::decode(&bytes_bytes[..], BDecodeOpt::default()).unwrap();
+
+        assert!(int != bytes);
+    }
+}
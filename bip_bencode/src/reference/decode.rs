@@ -3,7 +3,7 @@ use std::collections::btree_map::{Entry};
 use std::str::{self};
 
 use reference::bencode_ref::{BencodeRef, InnerBencodeRef};
-use reference::decode_opt::BDecodeOpt;
+use reference::decode_opt::{BDecodeOpt, DuplicatePolicy};
 use error::{BencodeParseError, BencodeParseErrorKind, BencodeParseResult};
 
 pub fn decode<'a>(bytes: &'a [u8], pos: usize, opts: BDecodeOpt, depth: usize) -> BencodeParseResult<(BencodeRef<'a>, usize)> {
@@ -15,20 +15,20 @@ pub fn decode<'a>(bytes: &'a [u8], pos: usize, opts: BDecodeOpt, depth: usize) -
     match curr_byte {
         ::INT_START  => {
             let (bencode, next_pos) = try!(decode_int(bytes, pos + 1, ::BEN_END));
-            Ok((InnerBencodeRef::Int(bencode, &bytes[pos..next_pos]).into(), next_pos))
+            Ok(((InnerBencodeRef::Int(bencode, &bytes[pos..next_pos]), bytes).into(), next_pos))
         },
         ::LIST_START => {
             let (bencode, next_pos) = try!(decode_list(bytes, pos + 1, opts, depth));
-            Ok((InnerBencodeRef::List(bencode, &bytes[pos..next_pos]).into(), next_pos))
+            Ok(((InnerBencodeRef::List(bencode, &bytes[pos..next_pos]), bytes).into(), next_pos))
         },
         ::DICT_START => {
             let (bencode, next_pos) = try!(decode_dict(bytes, pos + 1, opts, depth));
-            Ok((InnerBencodeRef::Dict(bencode, &bytes[pos..next_pos]).into(), next_pos))
+            Ok(((InnerBencodeRef::Dict(bencode, &bytes[pos..next_pos]), bytes).into(), next_pos))
         },
         ::BYTE_LEN_LOW...::BYTE_LEN_HIGH => {
-            let (bencode, next_pos) = try!(decode_bytes(bytes, pos));
+            let (bencode, next_pos) = try!(decode_bytes(bytes, pos, opts));
             // Include the length digit, don't increment position
-            Ok((InnerBencodeRef::Bytes(bencode, &bytes[pos..next_pos]).into(), next_pos))
+            Ok(((InnerBencodeRef::Bytes(bencode, &bytes[pos..next_pos]), bytes).into(), next_pos))
         },
         _ => Err(BencodeParseError::from_kind(BencodeParseErrorKind::InvalidByte{ pos: pos }))
     }
@@ -36,6 +36,12 @@ pub fn decode<'a>(bytes: &'a [u8], pos: usize, opts: BDecodeOpt, depth: usize) -
 
 fn decode_int<'a>(bytes: &'a [u8], pos: usize, delim: u8) -> BencodeParseResult<(i64, usize)> {
     let (_, begin_decode) = bytes.split_at(pos);
+
+    // Tokens are lowercase only, report an uppercase end marker instead of a missing delimiter
+    let upper_delim = delim.to_ascii_uppercase();
+    if let Some(upper_pos) = begin_decode.iter().take_while(|&&n| n != delim).position(|&n| n == upper_delim) {
+        return Err(BencodeParseError::from_kind(BencodeParseErrorKind::InvalidByte{ pos: pos + upper_pos }))
+    }
     
     let relative_end_pos = match begin_decode.iter().position(|n| *n == delim) {
         Some(end_pos) => end_pos,
@@ -43,6 +49,11 @@ fn decode_int<'a>(bytes: &'a [u8], pos: usize, delim: u8) -> BencodeParseResult<
     };
     let int_byte_slice = &begin_decode[..relative_end_pos];
     
+    // Explicit plus signs are not allowed (this would not be caught when converting)
+    if int_byte_slice.first() == Some(&b'+') {
+        return Err(BencodeParseError::from_kind(BencodeParseErrorKind::InvalidIntParseError{ pos: pos }))
+    }
+
     if int_byte_slice.len() > 1 {
         // Negative zero is not allowed (this would not be caught when converting)
         if int_byte_slice[0] == b'-' && int_byte_slice[1] == b'0' {
@@ -69,7 +80,7 @@ fn decode_int<'a>(bytes: &'a [u8], pos: usize, delim: u8) -> BencodeParseResult<
     }
 }
     
-fn decode_bytes<'a>(bytes: &'a [u8], pos: usize) -> BencodeParseResult<(&'a [u8], usize)> {
+fn decode_bytes<'a>(bytes: &'a [u8], pos: usize, opts: BDecodeOpt) -> BencodeParseResult<(&'a [u8], usize)> {
     let (num_bytes, start_pos) = try!(decode_int(bytes, pos, ::BYTE_LEN_END));
 
     if num_bytes < 0 {
@@ -80,6 +91,11 @@ fn decode_bytes<'a>(bytes: &'a [u8], pos: usize) -> BencodeParseResult<(&'a [u8]
     // a more helpful error message, otherwise, parsing will probably fail with an
     // unrelated message).
     let num_bytes = num_bytes as usize;
+
+    // Check the declared length before the input length, the declared length is untrusted
+    if num_bytes > opts.max_string_len() {
+        return Err(BencodeParseError::from_kind(BencodeParseErrorKind::StringTooLarge{ pos: pos, max: opts.max_string_len() }))
+    }
     
     if num_bytes > bytes[start_pos..].len() {
         return Err(BencodeParseError::from_kind(BencodeParseErrorKind::InvalidLengthOverflow{ pos: pos }))
@@ -96,6 +112,9 @@ fn decode_list<'a>(bytes: &'a [u8], pos: usize, opts: BDecodeOpt, depth: usize)
     let mut curr_byte = try!(peek_byte(bytes, curr_pos));
     
     while curr_byte != ::BEN_END {
+        if bencode_list.len() >= opts.max_collection_len() {
+            return Err(BencodeParseError::from_kind(BencodeParseErrorKind::CollectionTooLarge{ pos: curr_pos, max: opts.max_collection_len() }))
+        }
         let (bencode, next_pos) = try!(decode(bytes, curr_pos, opts, depth + 1));
         
         bencode_list.push(bencode);
@@ -115,7 +134,15 @@ fn decode_dict<'a>(bytes: &'a [u8], pos: usize, opts: BDecodeOpt, depth: usize)
     let mut curr_byte = try!(peek_byte(bytes, curr_pos));
     
     while curr_byte != ::BEN_END {
-        let (key_bytes, next_pos) = try!(decode_bytes(bytes, curr_pos));
+        if bencode_dict.len() >= opts.max_collection_len() {
+            return Err(BencodeParseError::from_kind(BencodeParseErrorKind::CollectionTooLarge{ pos: curr_pos, max: opts.max_collection_len() }))
+        }
+        // Keys must be byte strings, this also catches uppercase end markers
+        match curr_byte {
+            ::BYTE_LEN_LOW...::BYTE_LEN_HIGH => (),
+            _ => return Err(BencodeParseError::from_kind(BencodeParseErrorKind::InvalidByte{ pos: curr_pos }))
+        };
+        let (key_bytes, next_pos) = try!(decode_bytes(bytes, curr_pos, opts));
         
         // Spec says that the keys must be in alphabetical order
         match (bencode_dict.keys().last(), opts.check_key_sort()) {
@@ -127,9 +154,11 @@ fn decode_dict<'a>(bytes: &'a [u8], pos: usize, opts: BDecodeOpt, depth: usize)
         curr_pos = next_pos;
         
         let (value, next_pos) = try!(decode(bytes, curr_pos, opts, depth + 1));
-        match bencode_dict.entry(key_bytes) {
-            Entry::Vacant(n)   => n.insert(value),
-            Entry::Occupied(_) => {
+        match (bencode_dict.entry(key_bytes), opts.on_duplicate_keys()) {
+            (Entry::Vacant(n), _)                               => { n.insert(value); },
+            (Entry::Occupied(mut n), DuplicatePolicy::KeepLast) => { n.insert(value); },
+            (Entry::Occupied(_), DuplicatePolicy::KeepFirst)    => (),
+            (Entry::Occupied(_), DuplicatePolicy::Reject)       => {
                 return Err(BencodeParseError::from_kind(BencodeParseErrorKind::InvalidKeyDuplicates{ pos: curr_pos, key: key_bytes.to_vec() }))
             }
         };
@@ -154,7 +183,8 @@ mod tests {
 
     use access::bencode::BRefAccess;
     use reference::bencode_ref::BencodeRef;
-    use reference::decode_opt::BDecodeOpt;
+    use reference::decode_opt::{BDecodeOpt, DuplicatePolicy};
+    use error::BencodeParseErrorKind;
 
     // Positive Cases
     const GENERAL: &'static [u8] = b"d0:12:zero_len_key8:location17:udp://test.com:8011:nested dictd4:listli-500500eee6:numberi500500ee";
@@ -165,6 +195,8 @@ mod tests {
         b"l10:test_bytesi500ei0ei-500el12:nested_bytesed8:test_key10:test_valueee";
     const BYTES: &'static [u8] = b"5:\xC5\xE6\xBE\xE6\xF2";
     const BYTES_ZERO_LEN: &'static [u8] = b"0:";
+    const DICT_ZERO_LEN_KEY_VALUE: &'static [u8] = b"d0:0:e";
+    const LIST_ZERO_LEN_BYTES: &'static [u8] = b"l0:e";
     const INT: &'static [u8] = b"i500e";
     const INT_NEGATIVE: &'static [u8] = b"i-500e";
     const INT_ZERO: &'static [u8] = b"i0e";
@@ -179,6 +211,7 @@ mod tests {
     const INT_DOUBLE_ZERO: &'static [u8] = b"i00e";
     const INT_NEGATIVE_ZERO: &'static [u8] = b"i-0e";
     const INT_DOUBLE_NEGATIVE: &'static [u8] = b"i--5e";
+    const INT_PLUS_SIGN: &'static [u8] = b"i+5e";
     const DICT_UNORDERED_KEYS: &'static [u8] = b"d5:z_key5:value5:a_key5:valuee";
     const DICT_DUP_KEYS_SAME_DATA: &'static [u8] = b"d5:a_keyi0e5:a_keyi0ee";
     const DICT_DUP_KEYS_DIFF_DATA: &'static [u8] = b"d5:a_keyi0e5:a_key7:a_valuee";
@@ -251,7 +284,7 @@ mod tests {
 
     #[test]
     fn positive_decode_bytes() {
-        let bytes = super::decode_bytes(BYTES, 0).unwrap().0;
+        let bytes = super::decode_bytes(BYTES, 0, BDecodeOpt::default()).unwrap().0;
         assert_eq!(bytes.len(), 5);
         assert_eq!(bytes[0] as char, 'Å');
         assert_eq!(bytes[1] as char, 'æ');
@@ -262,10 +295,30 @@ mod tests {
 
     #[test]
     fn positive_decode_bytes_zero_len() {
-        let bytes = super::decode_bytes(BYTES_ZERO_LEN, 0).unwrap().0;
+        let bytes = super::decode_bytes(BYTES_ZERO_LEN, 0, BDecodeOpt::default()).unwrap().0;
         assert_eq!(bytes.len(), 0);
     }
 
+    #[test]
+    fn positive_decode_dict_zero_len_key_value() {
+        let bencode = BencodeRef::decode(DICT_ZERO_LEN_KEY_VALUE, BDecodeOpt::default()).unwrap();
+        let dict = bencode.dict().unwrap();
+
+        // Present but empty is distinct from missing
+        assert_eq!(Some(&b""[..]), dict.lookup(b"").map(|value| value.bytes().unwrap()));
+        assert!(dict.lookup(b"missing").is_none());
+    }
+
+    #[test]
+    fn positive_decode_list_zero_len_bytes() {
+        let bencode = BencodeRef::decode(LIST_ZERO_LEN_BYTES, BDecodeOpt::default()).unwrap();
+        let list = bencode.list().unwrap();
+
+        assert_eq!(1, list.len());
+        assert_eq!(&b""[..], list[0].bytes().unwrap());
+        assert_eq!("", list[0].str().unwrap());
+    }
+
     #[test]
     fn positive_decode_int() {
         let int_value = super::decode_int(INT, 1, ::BEN_END).unwrap().0;
@@ -297,6 +350,88 @@ mod tests {
         BencodeRef::decode(DICT_UNORDERED_KEYS, BDecodeOpt::default()).unwrap();
     }
 
+    #[test]
+    fn positive_decode_dict_dup_keys_keep_first() {
+        let opts = BDecodeOpt::default().set_on_duplicate_keys(DuplicatePolicy::KeepFirst);
+        let bencode = BencodeRef::decode(DICT_DUP_KEYS_DIFF_DATA, opts).unwrap();
+
+        assert_eq!(Some(0), bencode.dict().unwrap().lookup(b"a_key").unwrap().int());
+    }
+
+    #[test]
+    fn positive_decode_dict_dup_keys_keep_last() {
+        let opts = BDecodeOpt::default().set_on_duplicate_keys(DuplicatePolicy::KeepLast);
+        let bencode = BencodeRef::decode(DICT_DUP_KEYS_DIFF_DATA, opts).unwrap();
+
+        assert_eq!(Some("a_value"), bencode.dict().unwrap().lookup(b"a_key").unwrap().str());
+    }
+
+    #[test]
+    fn positive_decode_string_at_max_len() {
+        let opts = BDecodeOpt::default().set_max_string_len(4);
+
+        BencodeRef::decode(b"4:spam", opts).unwrap();
+        BencodeRef::decode(b"d4:spami0ee", opts).unwrap();
+    }
+
+    #[test]
+    fn negative_decode_string_too_large() {
+        let opts = BDecodeOpt::default().set_max_string_len(4);
+
+        // Declared length is far beyond the input, but the cap is checked first
+        match *BencodeRef::decode(b"li0e99999999999:spame", opts).unwrap_err().kind() {
+            BencodeParseErrorKind::StringTooLarge{ pos, max } => assert_eq!((4, 4), (pos, max)),
+            _ => panic!("Expected A StringTooLarge Error")
+        }
+        BencodeRef::decode(b"d5:spamsi0ee", opts).unwrap_err();
+    }
+
+    #[test]
+    fn positive_decode_collection_at_max_len() {
+        let opts = BDecodeOpt::default().set_max_collection_len(3);
+
+        BencodeRef::decode(b"li0ei1ei2ee", opts).unwrap();
+        BencodeRef::decode(b"d1:ai0e1:bi1e1:ci2ee", opts).unwrap();
+    }
+
+    #[test]
+    fn negative_decode_collection_too_large() {
+        let opts = BDecodeOpt::default().set_max_collection_len(2);
+
+        match *BencodeRef::decode(b"li0ei1ei2ee", opts).unwrap_err().kind() {
+            BencodeParseErrorKind::CollectionTooLarge{ pos, max } => assert_eq!((7, 2), (pos, max)),
+            _ => panic!("Expected A CollectionTooLarge Error")
+        }
+        BencodeRef::decode(b"d1:ai0e1:bi1e1:ci2ee", opts).unwrap_err();
+    }
+
+    #[test]
+    fn negative_decode_uppercase_markers() {
+        let cases: &[(&[u8], usize)] = &[(b"I5E", 0), (b"i5E", 2), (b"i5Ee", 2), (b"L0:E", 0), (b"l0:E", 3),
+                                         (b"D1:ai0eE", 0), (b"d1:ai0eE", 7), (b"d1:aI0ee", 4)];
+
+        for &(bytes, expected_pos) in cases.iter() {
+            match *BencodeRef::decode(bytes, BDecodeOpt::default()).unwrap_err().kind() {
+                BencodeParseErrorKind::InvalidByte{ pos } => assert_eq!(expected_pos, pos),
+                _ => panic!("Expected A InvalidByte Error")
+            }
+        }
+    }
+
+    #[test]
+    fn positive_decode_lenient_preset() {
+        BencodeRef::decode(DICT_UNORDERED_KEYS, BDecodeOpt::lenient()).unwrap();
+        BencodeRef::decode(DICT_DUP_KEYS_DIFF_DATA, BDecodeOpt::lenient()).unwrap();
+        BencodeRef::decode(PARTIAL, BDecodeOpt::lenient()).unwrap();
+    }
+
+    #[test]
+    fn negative_decode_strict_preset() {
+        BencodeRef::decode(DICT_UNORDERED_KEYS, BDecodeOpt::strict()).unwrap_err();
+        BencodeRef::decode(DICT_DUP_KEYS_DIFF_DATA, BDecodeOpt::strict()).unwrap_err();
+        BencodeRef::decode(PARTIAL, BDecodeOpt::strict()).unwrap_err();
+    }
+
     #[test]
     #[should_panic]
     fn negative_decode_bytes_neg_len() {
@@ -347,6 +482,12 @@ mod tests {
         super::decode_int(INT_DOUBLE_NEGATIVE, 1, ::BEN_END).unwrap().0;
     }
 
+    #[test]
+    #[should_panic]
+    fn negative_decode_int_plus_sign() {
+        super::decode_int(INT_PLUS_SIGN, 1, ::BEN_END).unwrap().0;
+    }
+
     #[test]
     #[should_panic]
     fn negative_decode_dict_unordered_keys() {
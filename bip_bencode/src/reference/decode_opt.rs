@@ -3,20 +3,85 @@ use std::default::Default;
 const DEFAULT_MAX_RECURSION:       usize = 50;
 const DEFAULT_CHECK_KEY_SORT:      bool = false;
 const DEFAULT_ENFORCE_FULL_DECODE: bool = true;
+const DEFAULT_ON_DUPLICATE_KEYS:   DuplicatePolicy = DuplicatePolicy::Reject;
+const DEFAULT_MAX_COLLECTION_LEN:  usize = ::std::usize::MAX;
+const DEFAULT_MAX_STRING_LEN:      usize = ::std::usize::MAX;
+
+const STRICT_MAX_COLLECTION_LEN: usize = 1000000;
+
+/// Policy for handling duplicate keys found within a dictionary.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum DuplicatePolicy {
+    /// Throw an error when a duplicate key is found.
+    Reject,
+    /// Keep the value of the first occurrence of the key.
+    KeepFirst,
+    /// Keep the value of the last occurrence of the key.
+    KeepLast
+}
 
 /// Stores decoding options for modifying decode behavior.
 #[derive(Copy, Clone)]
 pub struct BDecodeOpt {
     max_recursion:       usize,
     check_key_sort:      bool,
-    enforce_full_decode: bool
+    enforce_full_decode: bool,
+    on_duplicate_keys:   DuplicatePolicy,
+    max_collection_len:  usize,
+    max_string_len:      usize
 }
 
 impl BDecodeOpt {
     /// Create a new `BDecodeOpt` object.
     pub fn new(max_recursion: usize, check_key_sort: bool, enforce_full_decode: bool) -> BDecodeOpt {
         BDecodeOpt{ max_recursion: max_recursion, check_key_sort: check_key_sort,
-                    enforce_full_decode: enforce_full_decode }
+                    enforce_full_decode: enforce_full_decode, on_duplicate_keys: DEFAULT_ON_DUPLICATE_KEYS,
+                    max_collection_len: DEFAULT_MAX_COLLECTION_LEN, max_string_len: DEFAULT_MAX_STRING_LEN }
+    }
+
+    /// Create a new `BDecodeOpt` object that strictly follows the specification.
+    ///
+    /// Enforces a full decode, sorted keys, and no duplicate keys, while bounding both the recursion
+    /// depth and the number of entries in any single list or dictionary. Suitable for untrusted input.
+    pub fn strict() -> BDecodeOpt {
+        BDecodeOpt::new(DEFAULT_MAX_RECURSION, true, true)
+            .set_on_duplicate_keys(DuplicatePolicy::Reject)
+            .set_max_collection_len(STRICT_MAX_COLLECTION_LEN)
+    }
+
+    /// Create a new `BDecodeOpt` object that accepts common deviations found in real world torrents.
+    ///
+    /// Allows unsorted keys, trailing bytes after the bencode, and duplicate keys (keeping the last value).
+    pub fn lenient() -> BDecodeOpt {
+        BDecodeOpt::new(DEFAULT_MAX_RECURSION, false, false)
+            .set_on_duplicate_keys(DuplicatePolicy::KeepLast)
+    }
+
+    /// Set the policy used when a dictionary contains duplicate keys.
+    ///
+    /// Useful for salvaging torrents written by buggy creators; the spec disallows duplicates.
+    pub fn set_on_duplicate_keys(mut self, policy: DuplicatePolicy) -> BDecodeOpt {
+        self.on_duplicate_keys = policy;
+
+        self
+    }
+
+    /// Set the maximum number of entries allowed in any single list or dictionary.
+    ///
+    /// Unlimited by default, set this when decoding untrusted input.
+    pub fn set_max_collection_len(mut self, max_collection_len: usize) -> BDecodeOpt {
+        self.max_collection_len = max_collection_len;
+
+        self
+    }
+
+    /// Set the maximum length allowed for any single byte string, including dictionary keys.
+    ///
+    /// Unlimited by default. Checked against the declared length, before the input length.
+    pub fn set_max_string_len(mut self, max_string_len: usize) -> BDecodeOpt {
+        self.max_string_len = max_string_len;
+
+        self
     }
 
     /// Maximum limit allowed when decoding bencode.
@@ -38,6 +103,21 @@ impl BDecodeOpt {
     pub fn enforce_full_decode(&self) -> bool {
         self.enforce_full_decode
     }
+
+    /// Maximum number of entries allowed in any single list or dictionary.
+    pub fn max_collection_len(&self) -> usize {
+        self.max_collection_len
+    }
+
+    /// Maximum length allowed for any single byte string.
+    pub fn max_string_len(&self) -> usize {
+        self.max_string_len
+    }
+
+    /// Policy used when a dictionary contains duplicate keys.
+    pub fn on_duplicate_keys(&self) -> DuplicatePolicy {
+        self.on_duplicate_keys
+    }
 }
 
 impl Default for BDecodeOpt {
@@ -0,0 +1,84 @@
+use access::bencode::{BRefAccess, BencodeRefKind};
+use diff::{self, BencodeDiff};
+use error::{BencodeParseError, BencodeParseErrorKind, BencodeParseResult};
+use mutable::encode;
+use reference::bencode_ref::BencodeRef;
+use reference::decode_opt::BDecodeOpt;
+
+/// Encode the given bencode, decode the encoded bytes, and check that the result is structurally
+/// equal to the original bencode.
+///
+/// Decoding is performed with key sort checking enabled, so encoders emitting unsorted keys will
+/// be caught. Returns an error describing the first divergence found, if any.
+pub fn assert_round_trip<B>(bencode: &B) -> BencodeParseResult<()>
+    where B: BRefAccess, B::BKey: AsRef<[u8]> {
+    let mut bytes = Vec::new();
+    encode::encode(bencode, &mut bytes);
+
+    let opts = BDecodeOpt::new(BDecodeOpt::default().max_recursion(), true, true);
+    let decoded = try!(BencodeRef::decode(&bytes, opts));
+
+    check_equal(bencode, &decoded)
+}
+
+/// Check that the two bencode objects are equal, reporting the first entry of `bencode_diff` otherwise.
+fn check_equal<A, B>(expected: &A, actual: &B) -> BencodeParseResult<()>
+    where A: BRefAccess, A::BKey: AsRef<[u8]>, B: BRefAccess, B::BKey: AsRef<[u8]> {
+    match diff::bencode_diff(expected, actual).into_iter().next() {
+        Some(diff) => Err(BencodeParseError::from_kind(BencodeParseErrorKind::InvalidRoundTrip{ path: diff.path().to_owned(),
+                                                                                                details: describe(&diff) })),
+        None       => Ok(())
+    }
+}
+
+fn describe(diff: &BencodeDiff) -> String {
+    match *diff {
+        BencodeDiff::Added{ .. }   => "Value Was Added".to_owned(),
+        BencodeDiff::Removed{ .. } => "Value Was Removed".to_owned(),
+        BencodeDiff::Changed{ ref old, ref new, .. } => match (old.kind(), new.kind()) {
+            (BencodeRefKind::Int(a), BencodeRefKind::Int(b))     => format!("Integer {} Became {}", a, b),
+            (BencodeRefKind::Bytes(a), BencodeRefKind::Bytes(b)) => format!("Bytes {:?} Became {:?}", a, b),
+            _ => format!("{} Became {}", old.type_name(), new.type_name())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::default::Default;
+
+    use error::BencodeParseErrorKind;
+    use mutable::bencode_mut::BencodeMut;
+    use reference::bencode_ref::BencodeRef;
+    use reference::decode_opt::BDecodeOpt;
+
+    #[test]
+    fn positive_round_trip_unsorted_dict() {
+        let unsorted_bytes = b"d1:bli1e1:ae1:ai5ee";
+        let bencode = BencodeRef::decode(&unsorted_bytes[..], BDecodeOpt::default()).unwrap();
+
+        super::assert_round_trip(&bencode).unwrap();
+    }
+
+    #[test]
+    fn positive_round_trip_mut() {
+        super::assert_round_trip(&BencodeMut::new_int(-500)).unwrap();
+    }
+
+    #[test]
+    fn negative_check_equal_reports_path() {
+        let expected_bytes = b"d1:ali1ei2eee";
+        let actual_bytes = b"d1:ali1ei3eee";
+
+        let expected = BencodeRef::decode(&expected_bytes[..], BDecodeOpt::default()).unwrap();
+        let actual = BencodeRef::decode(&actual_bytes[..], BDecodeOpt::default()).unwrap();
+
+        match *super::check_equal(&expected, &actual).unwrap_err().kind() {
+            BencodeParseErrorKind::InvalidRoundTrip{ ref path, ref details } => {
+                assert_eq!("root[\"a\"][1]", path);
+                assert_eq!("Integer 2 Became 3", details);
+            },
+            _ => panic!("Expected An InvalidRoundTrip Error")
+        }
+    }
+}
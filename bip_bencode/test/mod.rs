@@ -1,6 +1,19 @@
 #[macro_use]
 extern crate bip_bencode;
 
+use bip_bencode::{BencodeRef, BRefAccess, BDecodeOpt, BConvert, BencodeConvertError, BencodeConvertErrorKind,
+                  BencodeParseErrorKind};
+
+struct TestConverter;
+
+impl BConvert for TestConverter {
+    type Error = BencodeConvertError;
+
+    fn handle_error(&self, error: BencodeConvertError) -> BencodeConvertError {
+        error
+    }
+}
+
 #[test]
 fn positive_ben_map_macro() {
     let result = (ben_map!{
@@ -17,4 +30,241 @@ fn positive_ben_list_macro() {
     )).encode();
 
     assert_eq!("li5ee".as_bytes(), &result[..]);
-}
\ No newline at end of file
+}
+
+#[test]
+fn positive_ben_bool_macro() {
+    assert_eq!("i1e".as_bytes(), &ben_bool!(true).encode()[..]);
+    assert_eq!("i0e".as_bytes(), &ben_bool!(false).encode()[..]);
+}
+
+#[test]
+fn positive_bool_access() {
+    let bencode = BencodeRef::decode(b"li1ei0ei2e1:1e", BDecodeOpt::default()).unwrap();
+    let list = bencode.list().unwrap();
+
+    assert_eq!(Some(true), list[0].bool());
+    assert_eq!(Some(false), list[1].bool());
+    assert_eq!(None, list[2].bool());
+    assert_eq!(None, list[3].bool());
+}
+
+#[test]
+fn positive_int_as_usize_access() {
+    let bencode = BencodeRef::decode(b"li0ei5ei-1e1:5e", BDecodeOpt::default()).unwrap();
+    let list = bencode.list().unwrap();
+
+    assert_eq!(Some(0), list[0].int_as_usize());
+    assert_eq!(Some(5), list[1].int_as_usize());
+    assert_eq!(None, list[2].int_as_usize());
+    assert_eq!(None, list[3].int_as_usize());
+}
+
+#[test]
+fn positive_byte_leaves() {
+    let bencode = BencodeRef::decode(b"d7:comment2:hi4:infod5:filesld4:pathl1:a1:beee6:lengthi5eee", BDecodeOpt::default()).unwrap();
+
+    let leaves = bencode.byte_leaves();
+    let expected: Vec<(Vec<&[u8]>, &[u8])> = vec![(vec![b"comment"], b"hi"),
+                                                  (vec![b"info", b"files", b"path"], b"a"),
+                                                  (vec![b"info", b"files", b"path"], b"b")];
+    assert_eq!(expected, leaves);
+}
+
+#[test]
+fn positive_list_binary_search_by() {
+    let bencode = BencodeRef::decode(b"li1ei3ei5ei7ee", BDecodeOpt::default()).unwrap();
+    let list = bencode.list().unwrap();
+
+    assert_eq!(Ok(2), list.binary_search_by(&mut |value| value.int().unwrap().cmp(&5)));
+    assert_eq!(Err(1), list.binary_search_by(&mut |value| value.int().unwrap().cmp(&2)));
+    assert_eq!(Err(4), list.binary_search_by(&mut |value| value.int().unwrap().cmp(&8)));
+}
+
+#[test]
+fn positive_list_iter_enumerate() {
+    let bencode = BencodeRef::decode(b"li0e4:spami2ee", BDecodeOpt::default()).unwrap();
+    let list = bencode.list().unwrap();
+
+    let invalid: Vec<usize> = list.iter().enumerate().filter(|&(_, b)| b.int().is_none()).map(|(i, _)| i).collect();
+    assert_eq!(vec![1], invalid);
+    assert!(list.get(3).is_none());
+}
+
+#[test]
+fn positive_lookup_path() {
+    let bencode = BencodeRef::decode(b"d4:infod4:named5:inneri5eeee", BDecodeOpt::default()).unwrap();
+
+    let value = TestConverter.lookup_path(&bencode, &[&b"info"[..], b"name", b"inner"]).unwrap();
+    assert_eq!(Some(5), value.int());
+}
+
+#[test]
+fn negative_lookup_path_missing_segment() {
+    let bencode = BencodeRef::decode(b"d4:infod4:named5:inneri5eeee", BDecodeOpt::default()).unwrap();
+
+    let error = TestConverter.lookup_path(&bencode, &[&b"info"[..], b"missing", b"inner"]).unwrap_err();
+    match *error.kind() {
+        BencodeConvertErrorKind::MissingKey{ ref key } => assert_eq!(&b"info/missing"[..], &key[..]),
+        _ => panic!("Expected A MissingKey Error")
+    }
+}
+
+#[test]
+fn negative_lookup_path_wrong_type_segment() {
+    let bencode = BencodeRef::decode(b"d4:infod4:named5:inneri5eeee", BDecodeOpt::default()).unwrap();
+
+    let error = TestConverter.lookup_path(&bencode, &[&b"info"[..], b"name", b"inner", b"deeper"]).unwrap_err();
+    match *error.kind() {
+        BencodeConvertErrorKind::WrongType{ ref key, .. } => assert_eq!(&b"info/name/inner"[..], &key[..]),
+        _ => panic!("Expected A WrongType Error")
+    }
+}
+
+#[test]
+fn positive_lookup_and_convert_or_default() {
+    let bencode = BencodeRef::decode(b"d7:privatei1e4:name5:dummye", BDecodeOpt::default()).unwrap();
+    let dict = bencode.dict().unwrap();
+
+    assert_eq!(1, TestConverter.lookup_and_convert_int_or(dict, b"private", 0).unwrap());
+    assert_eq!(0, TestConverter.lookup_and_convert_int_or(dict, b"missing", 0).unwrap());
+    assert_eq!(&b"dummy"[..], TestConverter.lookup_and_convert_bytes_or(dict, b"name", b"default").unwrap());
+    assert_eq!(&b"default"[..], TestConverter.lookup_and_convert_bytes_or(dict, b"missing", b"default").unwrap());
+}
+
+#[test]
+fn negative_lookup_and_convert_or_wrong_type() {
+    let bencode = BencodeRef::decode(b"d7:privatei1e4:name5:dummye", BDecodeOpt::default()).unwrap();
+    let dict = bencode.dict().unwrap();
+
+    match *TestConverter.lookup_and_convert_int_or(dict, b"name", 0).unwrap_err().kind() {
+        BencodeConvertErrorKind::WrongType{ ref key, .. } => assert_eq!(&b"name"[..], &key[..]),
+        _ => panic!("Expected A WrongType Error")
+    }
+    TestConverter.lookup_and_convert_bytes_or(dict, b"private", b"default").unwrap_err();
+}
+
+#[test]
+fn positive_lookup_and_convert_opt() {
+    let bencode = BencodeRef::decode(b"d5:filesle4:infod0:i0ee7:privatei1ee", BDecodeOpt::default()).unwrap();
+    let dict = bencode.dict().unwrap();
+
+    assert_eq!(Some(1), TestConverter.lookup_and_convert_int_opt(dict, b"private").unwrap());
+    assert_eq!(None, TestConverter.lookup_and_convert_int_opt(dict, b"missing").unwrap());
+    assert_eq!(Some(0), TestConverter.lookup_and_convert_list_opt(dict, b"files").unwrap().map(|l| l.len()));
+    assert!(TestConverter.lookup_and_convert_list_opt(dict, b"missing").unwrap().is_none());
+    assert!(TestConverter.lookup_and_convert_dict_opt(dict, b"info").unwrap().is_some());
+    assert!(TestConverter.lookup_and_convert_dict_opt(dict, b"missing").unwrap().is_none());
+}
+
+#[test]
+fn negative_lookup_and_convert_opt_wrong_type() {
+    let bencode = BencodeRef::decode(b"d5:filesle4:infod0:i0ee7:privatei1ee", BDecodeOpt::default()).unwrap();
+    let dict = bencode.dict().unwrap();
+
+    TestConverter.lookup_and_convert_int_opt(dict, b"info").unwrap_err();
+    assert!(TestConverter.lookup_and_convert_list_opt(dict, b"private").is_err());
+    match *TestConverter.lookup_and_convert_dict_opt(dict, b"files").map(|_| ()).unwrap_err().kind() {
+        BencodeConvertErrorKind::WrongType{ ref key, .. } => assert_eq!(&b"files"[..], &key[..]),
+        _ => panic!("Expected A WrongType Error")
+    }
+}
+
+#[test]
+fn negative_wrong_type_reports_found() {
+    let bencode = BencodeRef::decode(b"d12:piece length21:not an integer at alle", BDecodeOpt::default()).unwrap();
+    let dict = bencode.dict().unwrap();
+
+    let error = TestConverter.lookup_and_convert_int(dict, b"piece length").unwrap_err();
+    match *error.kind() {
+        BencodeConvertErrorKind::WrongType{ ref expected_type, ref found, .. } => {
+            assert_eq!("Integer", expected_type);
+            assert_eq!("Bytes Of Length 21 \"not an integer a\"...", found);
+        },
+        _ => panic!("Expected A WrongType Error")
+    }
+    assert!(error.to_string().contains("\"piece length\""));
+}
+
+#[test]
+fn positive_lookup_and_convert_fixed_bytes() {
+    let bencode = BencodeRef::decode(b"d2:id4:abcde", BDecodeOpt::default()).unwrap();
+    let dict = bencode.dict().unwrap();
+
+    let id: &[u8; 4] = TestConverter.lookup_and_convert_fixed_bytes(dict, b"id").unwrap();
+    assert_eq!(b"abcd", id);
+}
+
+#[test]
+fn negative_lookup_and_convert_fixed_bytes_wrong_len() {
+    let bencode = BencodeRef::decode(b"d2:id4:abcde", BDecodeOpt::default()).unwrap();
+    let dict = bencode.dict().unwrap();
+
+    let result: Result<&[u8; 20], _> = TestConverter.lookup_and_convert_fixed_bytes(dict, b"id");
+    match *result.unwrap_err().kind() {
+        BencodeConvertErrorKind::WrongType{ ref expected_type, .. } => assert_eq!("Bytes Of Length 20", expected_type),
+        _ => panic!("Expected A WrongType Error")
+    }
+}
+
+#[test]
+fn positive_encoded_len_matches_encode() {
+    let bencode = ben_map!{
+        "announce" => ben_bytes!("udp://dummy_domain.com:8989"),
+        "info" => ben_map!{
+            "files" => ben_list!(ben_int!(-100), ben_bytes!(""), ben_map!{}),
+            "pieces" => ben_bytes!(&[0u8; 2000][..])
+        }
+    };
+
+    assert_eq!(bencode.encode().len(), bencode.encoded_len());
+}
+
+#[test]
+fn positive_kind_predicates() {
+    let bencode = ben_map!{
+        "int" => ben_int!(1),
+        "bytes" => ben_bytes!("bytes"),
+        "list" => ben_list!()
+    };
+    let dict = bencode.dict().unwrap();
+
+    assert!(bencode.is_dict() && !bencode.is_list());
+    assert!(dict.lookup(b"int").unwrap().is_int());
+    assert!(dict.lookup(b"bytes").unwrap().is_bytes());
+    assert!(dict.lookup(b"list").unwrap().is_list());
+    assert_eq!("Bytes", dict.lookup(b"bytes").unwrap().type_name());
+}
+
+#[test]
+fn positive_decode_nested() {
+    let nested_bytes = (ben_map!{ "piece" => ben_int!(0) }).encode();
+    let bencode = ben_bytes!(&nested_bytes[..]);
+
+    let nested = bencode.decode_nested(BDecodeOpt::default()).unwrap();
+    assert_eq!(Some(0), nested.dict().unwrap().lookup(b"piece").unwrap().int());
+}
+
+#[test]
+fn negative_decode_nested_not_bytes() {
+    let error = ben_int!(5).decode_nested(BDecodeOpt::default()).unwrap_err();
+
+    match *error.kind() {
+        BencodeParseErrorKind::InvalidNestedType{ ref found } => assert_eq!("Integer", found),
+        _ => panic!("Expected An InvalidNestedType Error")
+    }
+}
+
+#[test]
+fn positive_dict_len_contains_key() {
+    let bencode = ben_map!{
+        "announce" => ben_bytes!("udp://dummy_domain.com:8989"),
+        "info" => ben_map!{}
+    };
+    let dict = bencode.dict().unwrap();
+
+    assert_eq!(2, dict.len());
+    assert!(dict.contains_key(b"info"));
+    assert!(!dict.contains_key(b"comment"));
+    assert!(dict.lookup(b"info").unwrap().dict().unwrap().is_empty());
+}
@@ -7,6 +7,24 @@ use bip_util::sha::ShaHash;
 use std::default::Default;
 use url::Url;
 
+/// Length of an `InfoHash` encoded as base32.
+const BASE32_INFO_HASH_LEN: usize = 32;
+
+/// Format the `InfoHash` as base32, as used by some magnet links.
+pub fn info_hash_to_base32(info_hash: &InfoHash) -> String {
+    base32::encode(base32::Alphabet::RFC4648 { padding: false }, info_hash.as_ref())
+}
+
+/// Parse an `InfoHash` from base32, returning None if the length or characters are invalid.
+pub fn info_hash_from_base32(s: &str) -> Option<InfoHash> {
+    if s.len() != BASE32_INFO_HASH_LEN {
+        return None;
+    }
+
+    base32::decode(base32::Alphabet::RFC4648 { padding: false }, s)
+        .and_then(|hash| ShaHash::from_hash(&hash[..]).ok())
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Topic {
     BitTorrentInfoHash(InfoHash),
@@ -28,13 +46,9 @@ impl Topic {
                 Ok(sha_hash) => Some(Topic::BitTorrentInfoHash(sha_hash)),
                 Err(_) => None,
             }
-        } else if s.starts_with("urn:btih:") && s.len() == 9 + 32 {
+        } else if s.starts_with("urn:btih:") && s.len() == 9 + BASE32_INFO_HASH_LEN {
             // BitTorrent Info Hash, base-32
-            base32::decode(base32::Alphabet::RFC4648 { padding: true }, &s[9..])
-                .and_then(|hash| match ShaHash::from_hash(&hash[..]) {
-                    Ok(sha_hash) => Some(Topic::BitTorrentInfoHash(sha_hash)),
-                    Err(_) => None,
-                })
+            info_hash_from_base32(&s[9..]).map(Topic::BitTorrentInfoHash)
         } else {
             None
         }
@@ -191,4 +205,20 @@ mod tests {
             "udp://exodus.desync.com:6969",
         ]);
     }
+
+    #[test]
+    fn test_base32_round_trip() {
+        let info_hash = ShaHash::from_hash(&[129, 225, 119, 226, 204, 0, 148, 59, 41, 252, 252, 99, 84, 87,
+                                             245, 117, 35, 114, 147, 176][..]).unwrap();
+        let encoded = ::info_hash_to_base32(&info_hash);
+
+        assert_eq!(encoded, "QHQXPYWMACKDWKP47RRVIV7VOURXFE5Q");
+        assert_eq!(::info_hash_from_base32(&encoded), Some(info_hash));
+    }
+
+    #[test]
+    fn test_base32_invalid_length() {
+        assert_eq!(::info_hash_from_base32("QHQXPYWMACKDWKP47RRVIV7VOURXFE5"), None);
+        assert_eq!(::info_hash_from_base32("QHQXPYWMACKDWKP47RRVIV7VOURXFE5QA"), None);
+    }
 }
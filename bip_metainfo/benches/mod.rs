@@ -1,13 +1,30 @@
 #![feature(test)]
 
+#[macro_use]
+extern crate bip_bencode;
 extern crate bip_metainfo;
 extern crate test;
 
 #[cfg(test)]
 mod benches {
-    use bip_metainfo::{Metainfo, MetainfoBuilder, DirectAccessor};
+    use bip_bencode::{BMutAccess};
+    use bip_metainfo::{self, Metainfo, MetainfoBuilder, DirectAccessor};
     use test::Bencher;
 
+    /// Metainfo file bytes with a 10 MB pieces field.
+    fn large_pieces_metainfo() -> Vec<u8> {
+        let pieces = vec![55u8; 10 * 1024 * 1024];
+
+        (ben_map!{
+            "info" => ben_map!{
+                "name"         => ben_bytes!("dummy_file_name"),
+                "length"       => ben_int!(512 * 1024 * 1024 * 1024),
+                "piece length" => ben_int!(1024 * 1024),
+                "pieces"       => ben_bytes!(&pieces[..])
+            }
+        }).encode()
+    }
+
     #[bench]
     fn bench_build_multi_kb_metainfo(b: &mut Bencher) {
         let file_content = vec![55u8; 10 * 1024 * 1024];
@@ -25,4 +42,18 @@ mod benches {
 
         b.iter(|| Metainfo::from_bytes(&metainfo[..]).unwrap());
     }
-}
\ No newline at end of file
+
+    #[bench]
+    fn bench_parse_large_pieces_metainfo(b: &mut Bencher) {
+        let metainfo = large_pieces_metainfo();
+
+        b.iter(|| Metainfo::from_bytes(&metainfo[..]).unwrap());
+    }
+
+    #[bench]
+    fn bench_info_hash_large_pieces_metainfo(b: &mut Bencher) {
+        let metainfo = large_pieces_metainfo();
+
+        b.iter(|| bip_metainfo::info_hash_from_bytes(&metainfo[..]).unwrap());
+    }
+}
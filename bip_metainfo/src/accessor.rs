@@ -125,7 +125,7 @@ impl Accessor for FileAccessor {
             self.absolute_path.iter().count() - 1
         };
 
-        for res_entry in WalkDir::new(&self.absolute_path).into_iter().filter(entry_file_filter) {
+        for res_entry in walk_sorted(&self.absolute_path).into_iter().filter(entry_file_filter) {
             let entry = try!(res_entry);
             let entry_metadata = try!(entry.metadata());
 
@@ -146,7 +146,7 @@ impl Accessor for FileAccessor {
     fn access_pieces<C>(&self, mut callback: C) -> io::Result<()>
         where C: for<'a> FnMut(PieceAccess<'a>) -> io::Result<()>
     {
-        for res_entry in WalkDir::new(&self.absolute_path).into_iter().filter(entry_file_filter) {
+        for res_entry in walk_sorted(&self.absolute_path).into_iter().filter(entry_file_filter) {
             let entry = try!(res_entry);
             let mut file = try!(File::open(entry.path()));
 
@@ -157,6 +157,11 @@ impl Accessor for FileAccessor {
     }
 }
 
+/// Walk the given path with entries sorted by file name, so files are always visited in the same order.
+fn walk_sorted(path: &Path) -> WalkDir {
+    WalkDir::new(path).sort_by(|a, b| a.file_name().cmp(b.file_name()))
+}
+
 /// Filter that yields true if the entry points to a file.
 fn entry_file_filter(res_entry: &walkdir::Result<DirEntry>) -> bool {
     res_entry.as_ref().map(|f| f.file_type().is_file()).unwrap_or(true)
@@ -1,4 +1,5 @@
 use std::iter::ExactSizeIterator;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use bip_bencode::{BencodeMut, BMutAccess, BRefAccess};
 use bip_util::sha::{self, ShaHash};
@@ -24,6 +25,9 @@ mod worker;
 // size which will shrink the pieces size which ensures we do not go outside of our max size.
 // This ensure we can generate good piece lengths for both large and small files.
 
+/// Default created by value set on a new `MetainfoBuilder`.
+pub const DEFAULT_CREATED_BY: &'static str = concat!("bip_metainfo/", env!("CARGO_PKG_VERSION"));
+
 // Maximum Piece Length Across The Board, Takes Priority Over Max Pieces Sizes
 // (Not Applied To Custom Lengths)
 const ALL_OPT_MAX_PIECE_LENGTH: usize = 16 * 1024 * 1024;
@@ -37,6 +41,12 @@ const FILE_SIZE_MIN_PIECE_LENGTH: usize = 1 * 1024 * 1024;
 const TRANSFER_MAX_PIECES_SIZE: usize = 60000;
 const TRANSFER_MIN_PIECE_LENGTH: usize = 1 * 1024;
 
+// Piece count optimization picks the smallest power of 2 piece length yielding at most the max
+// pieces count. Since doubling the piece length halves the pieces count, that leaves between
+// half the max and the max pieces, unless the total size is small enough to hit the min length.
+const PIECE_COUNT_MAX_PIECES: u64 = 2000;
+const PIECE_COUNT_MIN_PIECE_LENGTH: usize = 16 * 1024;
+
 /// Enumerates settings for piece length for generating a torrent file.
 pub enum PieceLength {
     /// Optimize piece length for torrent file size and file transfer.
@@ -45,6 +55,11 @@ pub enum PieceLength {
     OptFileSize,
     /// Optimize piece length for torrent file transfer.
     OptTransfer,
+    /// Optimize piece length for a piece count between 1000 and 2000 pieces.
+    ///
+    /// Piece length is a power of 2 between 16 KiB and 16 MiB, so very small or very large
+    /// torrents may fall outside of that piece count.
+    OptPieceCount,
     /// Custom piece length.
     Custom(usize),
 }
@@ -57,11 +72,29 @@ pub struct MetainfoBuilder<'a> {
 
 impl<'a> MetainfoBuilder<'a> {
     /// Create a new MetainfoBuilder with some default values set.
+    ///
+    /// Created by defaults to `DEFAULT_CREATED_BY`, and creation date defaults to the current time.
+    /// Either can be unset by passing None to the corresponding setter, for example for reproducible builds.
     pub fn new() -> MetainfoBuilder<'a> {
+        let secs_epoch = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs() as i64).ok();
+
         MetainfoBuilder {
             root: BencodeMut::new_dict(),
             info: InfoBuilder::new()
-        }
+        }.set_created_by(Some(DEFAULT_CREATED_BY)).set_creation_date(secs_epoch)
+    }
+
+    /// Unset the creation date, created by, and comment, so that building the same files always produces the same bytes.
+    ///
+    /// Info dictionary keys are always sorted, and files from a directory are always added in file name order.
+    pub fn reproducible(self) -> MetainfoBuilder<'a> {
+        self.no_timestamp().set_comment(None)
+    }
+
+    /// Unset the creation date and created by, which are set by default, so the output does not depend on when or
+    /// with which version it was built.
+    pub fn no_timestamp(self) -> MetainfoBuilder<'a> {
+        self.set_creation_date(None).set_created_by(None)
     }
 
     /// Set announce-list content
@@ -166,6 +199,22 @@ impl<'a> MetainfoBuilder<'a> {
         self
     }
 
+    /// Set or unset the source for the torrent file.
+    ///
+    /// The source is stored in the info dictionary, so it will change the info hash.
+    pub fn set_source(self, opt_source: Option<&'a str>) -> MetainfoBuilder<'a> {
+        self.set_source_bytes(opt_source.map(str::as_bytes))
+    }
+
+    /// Set or unset the source for the torrent file, which does not have to be valid UTF-8.
+    ///
+    /// The source is stored in the info dictionary, so it will change the info hash.
+    pub fn set_source_bytes(mut self, opt_source: Option<&'a [u8]>) -> MetainfoBuilder<'a> {
+        self.info = self.info.set_source_bytes(opt_source);
+
+        self
+    }
+
     /// Sets the piece length for the torrent file.
     pub fn set_piece_length(mut self, piece_length: PieceLength) -> MetainfoBuilder<'a> {
         self.info = self.info.set_piece_length(piece_length);
@@ -191,7 +240,7 @@ impl<'a> MetainfoBuilder<'a> {
     pub fn get_creation_date(&self) -> Option<i64> {
         let dict_access = self.root.dict().unwrap();
 
-        parse::parse_creation_date(dict_access)
+        parse::parse_creation_date(dict_access, false)
     }
 
     /// Get decoded value of comment key
@@ -250,6 +299,30 @@ impl<'a> InfoBuilder<'a> {
         self
     }
 
+    /// Set or unset the source for the torrent file.
+    ///
+    /// The source is stored in the info dictionary, so it will change the info hash.
+    pub fn set_source(self, opt_source: Option<&'a str>) -> InfoBuilder<'a> {
+        self.set_source_bytes(opt_source.map(str::as_bytes))
+    }
+
+    /// Set or unset the source for the torrent file, which does not have to be valid UTF-8.
+    ///
+    /// The source is stored in the info dictionary, so it will change the info hash.
+    pub fn set_source_bytes(mut self, opt_source: Option<&'a [u8]>) -> InfoBuilder<'a> {
+        {
+            let dict_access = self.info.dict_mut().unwrap();
+
+            if let Some(source) = opt_source {
+                dict_access.insert(parse::SOURCE_KEY.into(), ben_bytes!(source));
+            } else {
+                dict_access.remove(parse::SOURCE_KEY);
+            }
+        }
+
+        self
+    }
+
     /// Sets the piece length for the torrent file.
     pub fn set_piece_length(mut self, piece_length: PieceLength) -> InfoBuilder<'a> {
         self.piece_length = piece_length;
@@ -420,7 +493,19 @@ fn determine_piece_length(total_file_size: u64, piece_length: PieceLength) -> us
                                    TRANSFER_MAX_PIECES_SIZE,
                                    TRANSFER_MIN_PIECE_LENGTH)
         }
+        PieceLength::OptPieceCount => calculate_piece_count_length(total_file_size)
+    }
+}
+
+/// Calculate the minimum power of 2 piece length that keeps the pieces count at or below the max.
+fn calculate_piece_count_length(total_file_size: u64) -> usize {
+    let mut piece_length = PIECE_COUNT_MIN_PIECE_LENGTH;
+
+    while piece_length < ALL_OPT_MAX_PIECE_LENGTH && total_file_size > piece_length as u64 * PIECE_COUNT_MAX_PIECES {
+        piece_length *= 2;
     }
+
+    piece_length
 }
 
 /// Calculate the minimum power of 2 piece length for the given max pieces size and total file size.
@@ -450,3 +535,34 @@ fn map_pieces_list<I>(pieces: I) -> Vec<u8>
 
     concated_pieces
 }
+
+#[cfg(test)]
+mod tests {
+    use builder::PieceLength;
+
+    #[test]
+    fn positive_piece_count_length_small() {
+        assert_eq!(16 * 1024, super::determine_piece_length(0, PieceLength::OptPieceCount));
+        assert_eq!(16 * 1024, super::determine_piece_length(16 * 1024 * 2000, PieceLength::OptPieceCount));
+    }
+
+    #[test]
+    fn positive_piece_count_length_medium() {
+        let total_file_size = 4 * 1024 * 1024 * 1024;
+        let piece_length = super::determine_piece_length(total_file_size, PieceLength::OptPieceCount) as u64;
+
+        assert_eq!(4 * 1024 * 1024, piece_length);
+        let num_pieces = total_file_size / piece_length;
+        assert!(num_pieces > 1000 && num_pieces <= 2000);
+    }
+
+    #[test]
+    fn positive_piece_count_length_large() {
+        assert_eq!(16 * 1024 * 1024, super::determine_piece_length(1024 * 1024 * 1024 * 1024, PieceLength::OptPieceCount));
+    }
+
+    #[test]
+    fn positive_piece_count_length_custom_overrides() {
+        assert_eq!(1024, super::determine_piece_length(4 * 1024 * 1024 * 1024, PieceLength::Custom(1024)));
+    }
+}
@@ -24,5 +24,39 @@ error_chain! {
             description("Missing Data Detected In File")
             display("Missing Data Detected In File: {}", details)
         }
+        CorruptData {
+            details: String
+        } {
+            description("Corrupt Data Detected In File")
+            display("Corrupt Data Detected In File: {}", details)
+        }
+    }
+}
+
+impl ParseError {
+    /// Whether or not the error came from accessing the file system.
+    pub fn is_io_error(&self) -> bool {
+        match *self.kind() {
+            ParseErrorKind::Io(_) | ParseErrorKind::Dir(_) => true,
+            _ => false
+        }
+    }
+
+    /// Whether or not the error came from bytes that are not valid bencode.
+    pub fn is_corrupt_bencode(&self) -> bool {
+        match *self.kind() {
+            ParseErrorKind::BencodeParse(_) => true,
+            _ => false
+        }
+    }
+
+    /// Whether or not the error came from valid bencode that is not a valid metainfo file.
+    ///
+    /// This covers missing keys, values of the wrong type, and values that fail validation.
+    pub fn is_invalid_metainfo(&self) -> bool {
+        match *self.kind() {
+            ParseErrorKind::BencodeConvert(_) | ParseErrorKind::MissingData{ .. } | ParseErrorKind::CorruptData{ .. } => true,
+            _ => false
+        }
     }
 }
@@ -1,20 +1,19 @@
 //! Iterators over torrent file information.
 
-use bip_util::sha;
+use std::slice::{Chunks, Iter};
 
-use metainfo::File;
+use metainfo::{File, HashKind};
 
 /// Iterator over each File within the MetainfoFile.
+#[derive(Clone)]
 pub struct Files<'a> {
-    index: usize,
-    files: &'a [File],
+    files: Iter<'a, File>,
 }
 
 impl<'a> Files<'a> {
     pub fn new(files: &'a [File]) -> Files<'a> {
         Files {
-            index: 0,
-            files: files,
+            files: files.iter(),
         }
     }
 }
@@ -23,28 +22,36 @@ impl<'a> Iterator for Files<'a> {
     type Item = &'a File;
 
     fn next(&mut self) -> Option<&'a File> {
-        if let Some(file) = self.files.get(self.index) {
-            self.index += 1;
-            Some(file)
-        } else {
-            None
-        }
+        self.files.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.files.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Files<'a> {
+    fn next_back(&mut self) -> Option<&'a File> {
+        self.files.next_back()
     }
 }
 
+impl<'a> ExactSizeIterator for Files<'a> {}
+
 // ----------------------------------------------------------------------------//
 
 /// Iterator over each piece hash within the MetainfoFile.
+///
+/// Each hash yielded is `HashKind::len` bytes long.
+#[derive(Clone)]
 pub struct Pieces<'a> {
-    index: usize,
-    pieces: &'a [[u8; sha::SHA_HASH_LEN]],
+    pieces: Chunks<'a, u8>,
 }
 
 impl<'a> Pieces<'a> {
-    pub fn new(pieces: &'a [[u8; sha::SHA_HASH_LEN]]) -> Pieces<'a> {
+    pub fn new(pieces: &'a [u8], hash_kind: HashKind) -> Pieces<'a> {
         Pieces {
-            index: 0,
-            pieces: pieces,
+            pieces: pieces.chunks(hash_kind.len()),
         }
     }
 }
@@ -53,11 +60,18 @@ impl<'a> Iterator for Pieces<'a> {
     type Item = &'a [u8];
 
     fn next(&mut self) -> Option<&'a [u8]> {
-        if let Some(hash) = self.pieces.get(self.index) {
-            self.index += 1;
-            Some(hash)
-        } else {
-            None
-        }
+        self.pieces.next()
     }
-}
\ No newline at end of file
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.pieces.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Pieces<'a> {
+    fn next_back(&mut self) -> Option<&'a [u8]> {
+        self.pieces.next_back()
+    }
+}
+
+impl<'a> ExactSizeIterator for Pieces<'a> {}
@@ -65,6 +65,12 @@ extern crate crossbeam;
 extern crate walkdir;
 #[macro_use]
 extern crate error_chain;
+#[cfg(feature = "gzip")]
+extern crate flate2;
+#[cfg(feature = "encoding")]
+extern crate encoding_rs;
+#[cfg(feature = "mmap")]
+extern crate memmap2;
 
 #[cfg(test)]
 extern crate rand;
@@ -74,11 +80,16 @@ mod builder;
 pub mod error;
 mod metainfo;
 mod parse;
+mod parse_opt;
+mod verify;
 
 pub mod iter;
 
 pub use bip_util::bt::InfoHash;
 
 pub use accessor::{Accessor, IntoAccessor, DirectAccessor, FileAccessor, PieceAccess};
-pub use builder::{MetainfoBuilder, PieceLength, InfoBuilder};
-pub use metainfo::{Info, Metainfo, File};
+pub use builder::{MetainfoBuilder, PieceLength, InfoBuilder, DEFAULT_CREATED_BY};
+pub use metainfo::{Info, Metainfo, File, HashKind, TorrentSummary, compute_info_hash, info_hash_from_bytes,
+                    parse_info_hash_hex};
+pub use parse_opt::ParseOpt;
+pub use verify::VerifyReport;
@@ -1,26 +1,54 @@
 //! Accessing the fields of a Metainfo file.
-use std::path::{Path, PathBuf};
-use std::io;
-
-use bip_bencode::{BencodeRef, BDictAccess, BDecodeOpt, BRefAccess};
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::ops::Range;
+use std::path::{Component, Path, PathBuf};
+use std::io::{self, Read};
+use std::str;
+
+#[cfg(feature = "gzip")]
+use flate2::read::GzDecoder;
+#[cfg(feature = "encoding")]
+use encoding_rs::Encoding;
+#[cfg(feature = "mmap")]
+use memmap2::Mmap;
+
+use bip_bencode::{bencode_encode, BencodeMut, BencodeRef, BencodeRefKind, BDictAccess, BDecodeOpt, BRefAccess};
 use bip_util::bt::InfoHash;
-use bip_util::sha::{self, ShaHash};
+use bip_util::sha::{self, ShaHash, ShaHashBuilder};
 
 use accessor::{Accessor, PieceAccess, IntoAccessor};
 use builder::{MetainfoBuilder, InfoBuilder, PieceLength};
 use parse;
+use parse_opt::ParseOpt;
 use error::{ParseError, ParseErrorKind, ParseResult};
 use iter::{Files, Pieces};
+use verify::{self, VerifyReport};
+
+/// Maximum number of bytes a gzip compressed metainfo file may decompress to.
+#[cfg(feature = "gzip")]
+pub const MAX_GZIP_DECOMPRESSED_LEN: u64 = 64 * 1024 * 1024;
+
+/// Length of a SHA-256 hash, as used by v2 torrents (BEP-52).
+const SHA256_HASH_LEN: usize = 32;
 
 /// Contains optional metadata for a torrent file.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Metainfo {
-    comment: Option<String>,
+    comment: Option<Vec<u8>>,
+    comment_utf8: Option<String>,
     announce: Option<String>,
     announce_list: Option<Vec<Vec<String>>>,
+    http_seeds: Vec<String>,
     encoding: Option<String>,
-    created_by: Option<String>,
+    created_by: Option<Vec<u8>>,
     creation_date: Option<i64>,
+    piece_layers: BTreeMap<Vec<u8>, Vec<u8>>,
+    raw_fields: BTreeMap<Vec<u8>, Vec<u8>>,
+    // Present only when parsed with the preserve original option.
+    original: Option<Vec<u8>>,
+    // Raw bencode to replace (or remove, if None) in the original root dictionary.
+    edits: BTreeMap<Vec<u8>, Option<Vec<u8>>>,
     info: Info,
 }
 
@@ -28,10 +56,92 @@ impl Metainfo {
     /// Read a `Metainfo` from metainfo file bytes.
     pub fn from_bytes<B>(bytes: B) -> ParseResult<Metainfo>
         where B: AsRef<[u8]>
+    {
+        Metainfo::from_bytes_with_opt(bytes, ParseOpt::default())
+    }
+
+    /// Read a `Metainfo` from metainfo file bytes using the given parse options.
+    pub fn from_bytes_with_opt<B>(bytes: B, opt: ParseOpt) -> ParseResult<Metainfo>
+        where B: AsRef<[u8]>
     {
         let bytes_slice = bytes.as_ref();
 
-        parse_meta_bytes(bytes_slice)
+        parse_meta_bytes(bytes_slice, opt)
+    }
+
+    /// Read a `Metainfo` from the metainfo file at the given path.
+    ///
+    /// Errors opening or reading the file include the path in the error message.
+    pub fn from_file<P>(path: P) -> ParseResult<Metainfo>
+        where P: AsRef<Path>
+    {
+        let path = path.as_ref();
+        let mut bytes = Vec::new();
+
+        // Keep the io error kind, so that ParseError::is_io_error still applies
+        let mut file = try!(fs::File::open(path)
+            .map_err(|error| io::Error::new(error.kind(), format!("Failed To Open {}: {}", path.display(), error))));
+        try!(file.read_to_end(&mut bytes)
+            .map_err(|error| io::Error::new(error.kind(), format!("Failed To Read {}: {}", path.display(), error))));
+
+        Metainfo::from_bytes(bytes)
+    }
+
+    /// Read a `Metainfo` from the metainfo file at the given path, by memory mapping the file.
+    ///
+    /// Avoids reading very large metainfo files into memory before parsing them. Since `Metainfo`
+    /// copies what it needs out of the file, the mapping is dropped before returning.
+    ///
+    /// If the file is truncated or modified by another process while it is being parsed, the parse
+    /// may fail or see inconsistent data, and truncation can terminate the process with `SIGBUS` on
+    /// some platforms. Use `Metainfo::from_file` for files that may be modified concurrently.
+    #[cfg(feature = "mmap")]
+    pub fn from_mmap<P>(path: P) -> ParseResult<Metainfo>
+        where P: AsRef<Path>
+    {
+        let path = path.as_ref();
+
+        let file = try!(fs::File::open(path)
+            .map_err(|error| io::Error::new(error.kind(), format!("Failed To Open {}: {}", path.display(), error))));
+        // Safety: See the documentation above, the mapping is only read from and never outlives this call
+        let mmap = try!(unsafe { Mmap::map(&file) }
+            .map_err(|error| io::Error::new(error.kind(), format!("Failed To Map {}: {}", path.display(), error))));
+
+        Metainfo::from_bytes(&mmap[..])
+    }
+
+    /// Check that the given metainfo file bytes are a structurally valid torrent, without building a `Metainfo`.
+    ///
+    /// Accepts exactly the torrents that `Metainfo::from_bytes` accepts, but does not copy the pieces
+    /// or build a `Metainfo`. Useful for quickly accepting or rejecting uploads.
+    pub fn validate_bytes<B>(bytes: B) -> ParseResult<()>
+        where B: AsRef<[u8]>
+    {
+        validate_meta_bytes(bytes.as_ref())
+    }
+
+    /// Read a `Metainfo` from gzip compressed metainfo file bytes.
+    ///
+    /// Decompressed output is limited to `MAX_GZIP_DECOMPRESSED_LEN` bytes to guard against
+    /// decompression bombs. Truncated or invalid gzip streams return a `CorruptData` error.
+    #[cfg(feature = "gzip")]
+    pub fn from_gzip_bytes<B>(bytes: B) -> ParseResult<Metainfo>
+        where B: AsRef<[u8]>
+    {
+        let mut decompressed = Vec::new();
+        let mut decoder = GzDecoder::new(bytes.as_ref()).take(MAX_GZIP_DECOMPRESSED_LEN + 1);
+
+        if let Err(error) = decoder.read_to_end(&mut decompressed) {
+            let error_msg = format!("Gzip Stream Is Truncated Or Invalid: {}", error);
+            return Err(ParseError::from_kind(ParseErrorKind::CorruptData{ details: error_msg }))
+        }
+
+        if decompressed.len() as u64 > MAX_GZIP_DECOMPRESSED_LEN {
+            let error_msg = format!("Gzip Stream Decompresses To More Than {} Bytes", MAX_GZIP_DECOMPRESSED_LEN);
+            return Err(ParseError::from_kind(ParseErrorKind::CorruptData{ details: error_msg }))
+        }
+
+        Metainfo::from_bytes(decompressed)
     }
 
     /// Announce url for the main tracker of the metainfo file.
@@ -40,17 +150,70 @@ impl Metainfo {
     }
 
     /// List of announce urls.
+    ///
+    /// Empty urls and tiers are skipped, and an announce list with no remaining tiers is None.
     pub fn trackers(&self) -> Option<&Vec<Vec<String>>> {
         self.announce_list.as_ref()
     }
 
+    /// Owned copy of the announce url tiers.
+    ///
+    /// Empty if the metainfo file has no announce list.
+    pub fn trackers_owned(&self) -> Vec<Vec<String>> {
+        self.announce_list.clone().unwrap_or(Vec::new())
+    }
+
+    /// Announce url tiers with duplicate urls removed, for iterating over each tracker once.
+    ///
+    /// Uses the announce list if present, as BEP-12 requires, otherwise the main tracker as a single
+    /// tier. Only the first occurrence of a url, in tier order, is kept, and tiers left with no urls
+    /// are dropped. Urls are compared exactly, no normalization of case or trailing slashes is done.
+    pub fn normalized_trackers(&self) -> Vec<Vec<String>> {
+        let tiers = match self.announce_list {
+            Some(ref tiers) => tiers.clone(),
+            None            => self.announce.iter().map(|url| vec![url.clone()]).collect()
+        };
+        let mut seen = HashSet::new();
+
+        tiers.into_iter()
+            .map(|tier| tier.into_iter().filter(|url| seen.insert(url.clone())).collect::<Vec<String>>())
+            .filter(|tier| !tier.is_empty())
+            .collect()
+    }
+
+    /// List of http seed urls (BEP-17).
+    ///
+    /// These are distinct from web seeds (BEP-19), entries that are not http urls are skipped.
+    pub fn http_seeds(&self) -> &[String] {
+        &self.http_seeds
+    }
+
     /// Comment included within the metainfo file.
+    ///
+    /// Returns None if the comment is not valid UTF-8, see `comment_bytes`.
     pub fn comment(&self) -> Option<&str> {
+        self.comment_bytes().and_then(|c| str::from_utf8(c).ok())
+    }
+
+    /// Raw bytes of the comment included within the metainfo file.
+    pub fn comment_bytes(&self) -> Option<&[u8]> {
         self.comment.as_ref().map(|c| &c[..])
     }
 
+    /// Comment included within the metainfo file, preferring the `comment.utf-8` variant if present.
+    pub fn comment_utf8(&self) -> Option<&str> {
+        self.comment_utf8.as_ref().map(|c| &c[..]).or(self.comment())
+    }
+
     /// Person or group that created the metainfo file.
+    ///
+    /// Returns None if the created by is not valid UTF-8, see `created_by_bytes`.
     pub fn created_by(&self) -> Option<&str> {
+        self.created_by_bytes().and_then(|c| str::from_utf8(c).ok())
+    }
+
+    /// Raw bytes of the person or group that created the metainfo file.
+    pub fn created_by_bytes(&self) -> Option<&[u8]> {
         self.created_by.as_ref().map(|c| &c[..])
     }
 
@@ -64,11 +227,87 @@ impl Metainfo {
         self.creation_date
     }
 
+    /// Raw bencode for the creation date, as found in the metainfo file.
+    ///
+    /// Useful for inspecting non standard creation dates that could not be parsed.
+    pub fn creation_date_raw(&self) -> Option<&[u8]> {
+        self.raw_fields.get(parse::CREATION_DATE_KEY).map(|bytes| &bytes[..])
+    }
+
+    /// Merkle layers for the files of a v2 torrent (BEP-52), keyed by the pieces root of each file.
+    ///
+    /// Each layer is the concatenation of the SHA-256 hashes at the piece layer of that file's
+    /// Merkle tree. Empty if the metainfo file has no piece layers.
+    pub fn piece_layers(&self) -> &BTreeMap<Vec<u8>, Vec<u8>> {
+        &self.piece_layers
+    }
+
+    /// Raw bencode for a key within the root dictionary, reflecting any tracker edits.
+    ///
+    /// Keys with a dedicated accessor, such as `announce` or `created by`, are included. The `info`
+    /// and `piece layers` keys are not retained, since they are large and already available through
+    /// `Metainfo::info` and `Metainfo::piece_layers`.
+    pub fn extra_field(&self, key: &[u8]) -> Option<&[u8]> {
+        match self.edits.get(key) {
+            Some(opt_value) => opt_value.as_ref().map(|bytes| &bytes[..]),
+            None            => self.raw_fields.get(key).map(|bytes| &bytes[..])
+        }
+    }
+
+    /// Set the announce url for the main tracker of the metainfo file.
+    ///
+    /// Trackers live outside of the info dictionary, so this does not change the info hash.
+    pub fn set_main_tracker(&mut self, tracker_url: &str) {
+        self.announce = Some(tracker_url.to_owned());
+
+        self.edits.insert(parse::ANNOUNCE_URL_KEY.to_vec(), Some(ben_bytes!(tracker_url).encode()));
+    }
+
+    /// Append a tier of announce urls to the end of the announce list (BEP-12).
+    ///
+    /// Trackers live outside of the info dictionary, so this does not change the info hash.
+    /// Empty urls are skipped, and a tier with no remaining urls is not added. If there is no announce
+    /// list yet, the main tracker (if any) is kept as the first tier.
+    pub fn add_tracker_tier(&mut self, tier: Vec<String>) {
+        let tier: Vec<String> = tier.into_iter().filter(|url| !url.is_empty()).collect();
+        if tier.is_empty() {
+            return
+        }
+
+        let announce_list = {
+            // Clients ignore announce once announce-list is present, so keep the main tracker as the first tier
+            let seed_tiers = self.announce.iter().map(|url| vec![url.clone()]).collect();
+            let tiers = self.announce_list.get_or_insert(seed_tiers);
+            tiers.push(tier);
+
+            BencodeMut::list_from_iter(tiers.iter().map(|tier| {
+                BencodeMut::list_from_iter(tier.iter().map(|url| ben_bytes!(&url[..])))
+            }))
+        };
+
+        self.edits.insert(parse::ANNOUNCE_LIST_KEY.to_vec(), Some(announce_list.encode()));
+    }
+
     /// Info dictionary for the metainfo file.
     pub fn info(&self) -> &Info {
         &self.info
     }
 
+    /// Summary of the torrent, for display purposes.
+    pub fn summary(&self) -> TorrentSummary {
+        self.info.summary()
+    }
+
+    /// Whether or not the info dictionary keys were sorted in the metainfo file, see `Info::is_canonical`.
+    pub fn info_is_canonical(&self) -> bool {
+        self.info.is_canonical()
+    }
+
+    /// Number of bytes the info dictionary occupied in the metainfo file, see `Info::info_dict_size`.
+    pub fn info_dict_size(&self) -> usize {
+        self.info.info_dict_size()
+    }
+
     /// Retrieve the bencoded bytes for the `Metainfo` file.
     pub fn to_bytes(&self) -> Vec<u8> {
         // Since there are no file system accesses here, should be fine to unwrap
@@ -78,69 +317,296 @@ impl Metainfo {
             .set_comment(self.comment())
             .set_created_by(self.created_by())
             .set_private_flag(self.info().is_private())
+            .set_source_bytes(self.info().source_bytes())
             // TODO: Revisit this cast...
             .set_piece_length(PieceLength::Custom(self.info().piece_length() as usize))
             .build(1, &self.info, |_| ())
             .unwrap()
     }
+
+    /// Retrieve the bencoded bytes for the original metainfo file, with any edits applied.
+    ///
+    /// Unlike `to_bytes`, keys not modeled by `Metainfo` are retained and the info dictionary
+    /// is copied verbatim, so the info hash never changes. Without edits, the original bytes are
+    /// returned as is; otherwise the root dictionary is written out in sorted key order.
+    ///
+    /// Returns None if the metainfo was not parsed with `ParseOpt::set_preserve_original`.
+    pub fn reserialize(&self) -> Option<Vec<u8>> {
+        let original = match self.original {
+            Some(ref original) => original,
+            None               => return None
+        };
+
+        if self.edits.is_empty() {
+            return Some(original.clone())
+        }
+
+        // Original bytes were parsed successfully once already
+        let root_bencode = BencodeRef::decode(original, BDecodeOpt::default()).unwrap();
+        let root_dict = root_bencode.dict().unwrap();
+
+        let mut fields: BTreeMap<&[u8], &[u8]> = root_dict.to_list().into_iter()
+            .map(|(&key, value)| (key, value.buffer()))
+            .collect();
+        for (key, opt_value) in self.edits.iter() {
+            match *opt_value {
+                Some(ref value) => { fields.insert(key, value); },
+                None            => { fields.remove(&key[..]); }
+            }
+        }
+
+        let mut bytes = Vec::with_capacity(original.len());
+        bytes.push(b'd');
+        for (key, value) in fields {
+            bytes.extend_from_slice(key.len().to_string().as_bytes());
+            bytes.push(b':');
+            bytes.extend_from_slice(key);
+            bytes.extend_from_slice(value);
+        }
+        bytes.push(b'e');
+
+        Some(bytes)
+    }
 }
 
 impl From<Info> for Metainfo {
     fn from(info: Info) -> Metainfo {
         Metainfo{
             comment: None,
+            comment_utf8: None,
             announce: None,
             announce_list: None,
+            http_seeds: Vec::new(),
             encoding: None,
             created_by: None,
             creation_date: None,
+            piece_layers: BTreeMap::new(),
+            raw_fields: BTreeMap::new(),
+            original: None,
+            edits: BTreeMap::new(),
             info: info
         }
     }
 }
 
+/// Compute the info hash for the given info dictionary, such as one built with the bencode macros.
+///
+/// The info dictionary is encoded with its keys sorted before hashing, so keys do not need to be
+/// inserted in order. For an info dictionary decoded from bytes with unsorted keys, this differs
+/// from the hash of the source bytes that other clients use, see `Info::is_canonical`; prefer
+/// `info_hash_from_bytes` when the source bytes are available.
+pub fn compute_info_hash<B>(info: &B) -> InfoHash
+    where B: BRefAccess, B::BKey: AsRef<[u8]> {
+    InfoHash::from_bytes(&bencode_encode(info))
+}
+
+/// Compute the info hash for the given metainfo file bytes.
+///
+/// Only locates the info dictionary and hashes its bytes, the files and pieces are not parsed.
+pub fn info_hash_from_bytes(bytes: &[u8]) -> ParseResult<InfoHash> {
+    let root_bencode = try!(BencodeRef::decode(bytes, BDecodeOpt::default()));
+    let root_dict = try!(parse::parse_root_dict(&root_bencode));
+
+    let info_bencode = try!(parse::parse_info_bencode(root_dict));
+    try!(parse::parse_root_dict(info_bencode));
+
+    Ok(InfoHash::from_bytes(info_bencode.buffer()))
+}
+
+/// Validates the given metainfo bytes with the same checks as parsing, without copying the pieces.
+fn validate_meta_bytes(bytes: &[u8]) -> ParseResult<()> {
+    let opt = ParseOpt::default();
+
+    let root_bencode = try!(BencodeRef::decode(bytes, BDecodeOpt::default()));
+    let root_dict = try!(parse::parse_root_dict(&root_bencode));
+    if let Some(layers_dict) = parse::parse_piece_layers(root_dict) {
+        try!(parse_piece_layers(layers_dict));
+    }
+
+    let info_bencode = try!(parse::parse_info_bencode(root_dict));
+    let info_dict = try!(parse::parse_root_dict(info_bencode));
+    try!(parse_name_lossy(info_dict, opt.lenient()));
+
+    validate_info_files(info_dict, opt)
+}
+
+/// Parse an info hash from its 40 character hex representation, in either case.
+pub fn parse_info_hash_hex(hex: &str) -> ParseResult<InfoHash> {
+    if hex.len() != sha::SHA_HASH_LEN * 2 {
+        let error_msg = format!("Info Hash Hex Length Of {} Is Invalid", hex.len());
+        return Err(ParseError::from_kind(ParseErrorKind::CorruptData{ details: error_msg }))
+    }
+
+    let mut hash = [0u8; sha::SHA_HASH_LEN];
+    for (byte, hex_pair) in hash.iter_mut().zip(hex.as_bytes().chunks(2)) {
+        match (hex_digit(hex_pair[0]), hex_digit(hex_pair[1])) {
+            (Some(high), Some(low)) => *byte = (high << 4) | low,
+            _ => return Err(ParseError::from_kind(ParseErrorKind::CorruptData{ details: "Info Hash Hex Contains A Non Hex Character".to_owned() }))
+        }
+    }
+
+    Ok(hash.into())
+}
+
+/// Value of the given ascii hex digit, if it is one.
+fn hex_digit(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None
+    }
+}
+
 /// Parses the given metainfo bytes and builds a Metainfo from them.
-fn parse_meta_bytes(bytes: &[u8]) -> ParseResult<Metainfo> {
+fn parse_meta_bytes(bytes: &[u8], opt: ParseOpt) -> ParseResult<Metainfo> {
     let root_bencode = try!(BencodeRef::decode(bytes, BDecodeOpt::default()));
     let root_dict = try!(parse::parse_root_dict(&root_bencode));
 
     let announce = parse::parse_announce_url(root_dict).map(|e| e.to_owned());
 
-    let opt_announce_list = {
-        parse::parse_announce_list(root_dict)
-            .and_then(|list| Some(parse::convert_announce_list(list)))
-            .or(None)
-    };
+    // An announce list without any usable tiers is treated as if it were missing
+    let opt_announce_list = parse::parse_announce_list(root_dict)
+        .map(parse::convert_announce_list)
+        .and_then(|tiers| if tiers.is_empty() { None } else { Some(tiers) });
 
-    let opt_comment = parse::parse_comment(root_dict).map(|e| e.to_owned());
+    let http_seeds = parse::parse_http_seeds(root_dict)
+        .map(parse::convert_http_seeds)
+        .unwrap_or(Vec::new());
+
+    let opt_comment = parse::parse_comment_bytes(root_dict).map(|e| e.to_vec());
+    let opt_comment_utf8 = parse::parse_comment_utf8(root_dict).map(|e| e.to_owned());
     let opt_encoding = parse::parse_encoding(root_dict).map(|e| e.to_owned());
-    let opt_created_by = parse::parse_created_by(root_dict).map(|e| e.to_owned());
-    let opt_creation_date = parse::parse_creation_date(root_dict);
+    let opt_created_by = parse::parse_created_by_bytes(root_dict).map(|e| e.to_vec());
+    let opt_creation_date = parse::parse_creation_date(root_dict, opt.lenient());
+    let piece_layers = match parse::parse_piece_layers(root_dict) {
+        Some(layers_dict) => try!(parse_piece_layers(layers_dict)),
+        None              => BTreeMap::new()
+    };
+    let raw_fields = parse_raw_fields(root_dict, &[parse::INFO_KEY, parse::PIECE_LAYERS_KEY]);
 
     let info_bencode = try!(parse::parse_info_bencode(root_dict));
-    let info = try!(parse_info_dictionary(info_bencode));
+    let info = try!(parse_info_dictionary(info_bencode, opt));
+
+    let opt_original = if opt.preserve_original() { Some(root_bencode.buffer().to_vec()) } else { None };
 
     Ok(Metainfo {
         comment: opt_comment,
+        comment_utf8: opt_comment_utf8,
         announce: announce,
         announce_list: opt_announce_list,
+        http_seeds: http_seeds,
         encoding: opt_encoding,
         created_by: opt_created_by,
         creation_date: opt_creation_date,
+        piece_layers: piece_layers,
+        raw_fields: raw_fields,
+        original: opt_original,
+        edits: BTreeMap::new(),
         info: info
     })
 }
 
+/// Copies out the piece layers, validating that each root and layer is made up of whole SHA-256 hashes.
+fn parse_piece_layers<'a>(layers_dict: &BDictAccess<&'a [u8], BencodeRef<'a>>) -> ParseResult<BTreeMap<Vec<u8>, Vec<u8>>> {
+    let mut piece_layers = BTreeMap::new();
+
+    for (&root, layer_bencode) in layers_dict.to_list() {
+        let layer = try!(parse::parse_piece_layer_bytes(layer_bencode));
+
+        if root.len() != SHA256_HASH_LEN || layer.is_empty() || layer.len() % SHA256_HASH_LEN != 0 {
+            let error_msg = format!("Piece Layer Root Length Of {} And Layer Length Of {} Is Invalid", root.len(), layer.len());
+            return Err(ParseError::from_kind(ParseErrorKind::MissingData{ details: error_msg }))
+        }
+
+        piece_layers.insert(root.to_vec(), layer.to_vec());
+    }
+
+    Ok(piece_layers)
+}
+
+// ----------------------------------------------------------------------------//
+
+/// Commonly displayed information about a torrent.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TorrentSummary {
+    name:         String,
+    total_length: u64,
+    num_files:    usize,
+    num_pieces:   usize,
+    info_hash:    InfoHash
+}
+
+impl TorrentSummary {
+    /// Name of the torrent, see `Info::name_utf8`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Total length in bytes of all files, see `Info::total_length`.
+    pub fn total_length(&self) -> u64 {
+        self.total_length
+    }
+
+    /// Number of files, including any padding files.
+    pub fn num_files(&self) -> usize {
+        self.num_files
+    }
+
+    /// Number of pieces.
+    pub fn num_pieces(&self) -> usize {
+        self.num_pieces
+    }
+
+    /// Hash to uniquely identify the torrent.
+    pub fn info_hash(&self) -> InfoHash {
+        self.info_hash
+    }
+}
+
 // ----------------------------------------------------------------------------//
 
+/// Hash function used for the piece hashes of a torrent.
+///
+/// Only the SHA-1 pieces field is parsed for now; SHA-256 piece layers (BEP-52) are exposed
+/// separately through `Metainfo::piece_layers`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum HashKind {
+    /// SHA-1, used by v1 torrents and the pieces field of hybrid torrents.
+    Sha1
+}
+
+impl HashKind {
+    /// Length of a single hash, in bytes.
+    pub fn len(&self) -> usize {
+        match *self {
+            HashKind::Sha1 => sha::SHA_HASH_LEN
+        }
+    }
+}
+
 /// Contains directory and checksum data for a torrent file.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Info {
     info_hash:      InfoHash,
+    // Present only for hybrid v1/v2 torrents.
+    info_hash_v2:   Option<InfoHash>,
+    is_canonical:   bool,
+    // Info dictionary as found in the source bytes, the only copy of the piece hashes.
+    info_bytes:     Vec<u8>,
     files:          Vec<File>,
-    pieces:         Vec<[u8; sha::SHA_HASH_LEN]>,
+    // Concatenated piece hashes within info_bytes, each hash_kind.len() bytes long.
+    pieces:         Range<usize>,
+    hash_kind:      HashKind,
     piece_len:      u64,
     is_private:     Option<bool>,
+    source:         Option<Vec<u8>>,
+    similar:        Vec<InfoHash>,
+    collections:    Vec<String>,
+    name:           String,
+    name_utf8:      Option<String>,
+    // Raw bencode for every key, as ranges within info_bytes.
+    field_spans:    BTreeMap<Vec<u8>, Range<usize>>,
     // Present only for multi file torrents.
     file_directory: Option<PathBuf>,
 }
@@ -149,10 +615,17 @@ impl Info {
     /// Read an `Info` from info dictionary bytes.
     pub fn from_bytes<B>(bytes: B) -> ParseResult<Info>
         where B: AsRef<[u8]>
+    {
+        Info::from_bytes_with_opt(bytes, ParseOpt::default())
+    }
+
+    /// Read an `Info` from info dictionary bytes using the given parse options.
+    pub fn from_bytes_with_opt<B>(bytes: B, opt: ParseOpt) -> ParseResult<Info>
+        where B: AsRef<[u8]>
     {
         let bytes_slice = bytes.as_ref();
 
-        parse_info_bytes(bytes_slice)
+        parse_info_bytes(bytes_slice, opt)
     }
 
     /// Hash to uniquely identify this torrent.
@@ -160,6 +633,36 @@ impl Info {
         self.info_hash
     }
 
+    /// SHA-1 hash of the info dictionary, identical to `Info::info_hash`.
+    pub fn info_hash_v1(&self) -> InfoHash {
+        self.info_hash
+    }
+
+    /// Truncated SHA-256 hash of the info dictionary (BEP-52), if this is a hybrid torrent.
+    pub fn info_hash_v2(&self) -> Option<InfoHash> {
+        self.info_hash_v2
+    }
+
+    /// Whether or not every dictionary key in the info dictionary was sorted in the source bytes.
+    ///
+    /// Clients that re-encode the info dictionary before hashing it will compute a different info
+    /// hash for non canonical info dictionaries than clients that hash the source bytes.
+    pub fn is_canonical(&self) -> bool {
+        self.is_canonical
+    }
+
+    /// Number of bytes the info dictionary occupied in the source bytes.
+    pub fn info_dict_size(&self) -> usize {
+        self.info_bytes.len()
+    }
+
+    /// Whether or not this torrent can be used in both v1 and v2 swarms.
+    ///
+    /// Determined by the presence of `meta version` 2 and a `file tree` alongside the v1 `pieces`.
+    pub fn is_hybrid(&self) -> bool {
+        self.info_hash_v2.is_some()
+    }
+
     /// Some file directory if this is a multi-file torrent, otherwise None.
     ///
     /// If you want to check to see if this is a multi-file torrent, you should
@@ -169,6 +672,33 @@ impl Info {
         self.file_directory.as_ref().map(|d| d.as_ref())
     }
 
+    /// Whether or not this is a multi-file torrent.
+    ///
+    /// Determined by the presence of the `files` key, rather than the number of files.
+    pub fn is_multi_file(&self) -> bool {
+        self.file_directory.is_some()
+    }
+
+    /// Name of the torrent, preferring the `name.utf-8` variant if present.
+    ///
+    /// This is the file name for single file torrents, and the directory name for multi file torrents.
+    pub fn name_utf8(&self) -> &str {
+        self.name_utf8.as_ref().unwrap_or(&self.name)
+    }
+
+    /// Total length in bytes of all files, including any padding files.
+    ///
+    /// This is the exact length (`xl`) advertised in magnet links.
+    pub fn total_length(&self) -> u64 {
+        self.files.iter().fold(0u64, |acc, file| acc.saturating_add(file.length()))
+    }
+
+    /// Summary of the torrent, for display purposes.
+    pub fn summary(&self) -> TorrentSummary {
+        TorrentSummary{ name: self.name_utf8().to_owned(), total_length: self.total_length(),
+                        num_files: self.files.len(), num_pieces: self.pieces().len(), info_hash: self.info_hash }
+    }
+
     /// Length in bytes of each piece.
     pub fn piece_length(&self) -> u64 {
         self.piece_len
@@ -179,13 +709,69 @@ impl Info {
         self.is_private
     }
 
-    /// Iterator over each of the pieces SHA-1 hash.
+    /// Source tag for the torrent, typically injected by private trackers.
+    ///
+    /// Since the source is part of the info dictionary, it contributes to the info hash.
+    /// Returns None if the source is not valid UTF-8, see `source_bytes`.
+    pub fn source(&self) -> Option<&str> {
+        self.source_bytes().and_then(|s| str::from_utf8(s).ok())
+    }
+
+    /// Raw bytes of the source tag for the torrent.
+    pub fn source_bytes(&self) -> Option<&[u8]> {
+        self.source.as_ref().map(|s| &s[..])
+    }
+
+    /// Info hashes of torrents that may share files with this torrent (BEP-38).
+    ///
+    /// Clients can use these to locate existing data before downloading.
+    pub fn similar_torrents(&self) -> &[InfoHash] {
+        &self.similar
+    }
+
+    /// Collections that this torrent belongs to (BEP-38).
+    pub fn collections(&self) -> &[String] {
+        &self.collections
+    }
+
+    /// Raw bencode for any key within the info dictionary.
+    ///
+    /// Keys with a dedicated accessor, such as `pieces` or `name`, are included, and the bytes are
+    /// returned exactly as they appeared in the info dictionary. Fields are sliced out of the single
+    /// retained copy of the info dictionary, so nothing is copied or decoded.
+    pub fn extra_field(&self, key: &[u8]) -> Option<&[u8]> {
+        self.field_spans.get(key).map(|span| &self.info_bytes[span.clone()])
+    }
+
+    /// Hash function used for each of the piece hashes.
+    pub fn hash_kind(&self) -> HashKind {
+        self.hash_kind
+    }
+
+    /// Iterator over each of the pieces hashes.
     ///
     /// Ordering of pieces yielded in the iterator is guaranteed to be the order in
     /// which they are found in the torrent file as this is necessary to refer to
     /// pieces by their index to other peers.
     pub fn pieces<'a>(&'a self) -> Pieces<'a> {
-        Pieces::new(&self.pieces)
+        Pieces::new(&self.info_bytes[self.pieces.clone()], self.hash_kind)
+    }
+
+    /// Check whether the hash of the given data matches the piece hash at the given index.
+    ///
+    /// Returns None if the index is out of range.
+    pub fn verify_piece(&self, index: usize, data: &[u8]) -> Option<bool> {
+        self.pieces().nth(index).map(|hash| ShaHash::from_bytes(data) == *hash)
+    }
+
+    /// Verify the content of each file against the piece hashes.
+    ///
+    /// The `open` callback is given each path from `Info::full_paths`, relative to the download
+    /// location, and should return a reader over that file. Files are streamed through in order,
+    /// in fixed size chunks, so memory use does not depend on the piece length.
+    pub fn verify_files<F, R>(&self, open: F) -> VerifyReport
+        where F: FnMut(&Path) -> io::Result<R>, R: Read {
+        verify::verify_files(self, open)
     }
 
     /// Iterator over each file within the torrent file.
@@ -197,11 +783,84 @@ impl Info {
         Files::new(&self.files)
     }
 
+    /// Iterator over each file within the torrent file, skipping padding files.
+    ///
+    /// Ordering of files yielded is the same as `Info::files`.
+    pub fn content_files<'a>(&'a self) -> impl Iterator<Item = &'a File> + 'a {
+        self.files().filter(|file| !file.is_padding())
+    }
+
+    /// Iterator over each file within the torrent file whose length is greater than the given length.
+    ///
+    /// Ordering of files yielded is the same as `Info::files`.
+    pub fn files_larger_than<'a>(&'a self, length: u64) -> impl Iterator<Item = &'a File> + 'a {
+        self.files().filter(move |file| file.length() > length)
+    }
+
+    /// Range of piece indices covering each file, in the same order as `Info::files`.
+    ///
+    /// The flag is true if the first or last piece of the file also covers bytes of another file,
+    /// meaning the file cannot be verified on its own. Empty files have an empty range and a false
+    /// flag, as do all files if the piece length is 0.
+    pub fn file_piece_ranges(&self) -> Vec<(Range<usize>, bool)> {
+        self.files_with_piece_ranges().map(|(_, range, shares)| (range, shares)).collect()
+    }
+
+    /// Iterator over each file along with the range of piece indices covering it.
+    ///
+    /// Ordering of files yielded is the same as `Info::files`, see `Info::file_piece_ranges` for
+    /// how ranges are computed for empty files and files sharing pieces.
+    pub fn files_with_pieces<'a>(&'a self) -> impl Iterator<Item = (&'a File, Range<usize>)> + 'a {
+        self.files_with_piece_ranges().map(|(file, range, _)| (file, range))
+    }
+
+    /// Iterator over each file along with its piece range, and whether it shares an end piece.
+    fn files_with_piece_ranges<'a>(&'a self) -> impl Iterator<Item = (&'a File, Range<usize>, bool)> + 'a {
+        let piece_len = self.piece_len;
+        let total_len = self.total_length();
+
+        self.files.iter().scan(0u64, move |file_start, file| {
+            // Parsing rejects overflowing totals, so this only saturates for a corrupt file list
+            let file_end = file_start.saturating_add(file.length());
+            let first_piece = if piece_len == 0 { 0 } else { *file_start / piece_len };
+
+            let (piece_range, shares) = if piece_len == 0 || file.length() == 0 {
+                (first_piece as usize..first_piece as usize, false)
+            } else {
+                let end_piece = file_end / piece_len + if file_end % piece_len != 0 { 1 } else { 0 };
+                let shares_first = *file_start % piece_len != 0;
+                let shares_last = file_end % piece_len != 0 && file_end != total_len;
+
+                (first_piece as usize..end_piece as usize, shares_first || shares_last)
+            };
+            *file_start = file_end;
+
+            Some((file, piece_range, shares))
+        })
+    }
+
+    /// Iterator over the relative path of each file, joined with the directory for multi file torrents.
+    ///
+    /// Yields None for any file whose path, or the directory it is joined to, is not safe (see
+    /// `File::safe_path`), so yielded paths never escape the download location they are joined to.
+    pub fn full_paths<'a>(&'a self) -> impl Iterator<Item = Option<PathBuf>> + 'a {
+        let opt_directory = match self.directory() {
+            Some(directory) if directory.to_str().map_or(false, is_safe_path_element) => Some(directory),
+            Some(_) => None,
+            None    => Some(Path::new(""))
+        };
+
+        self.files().map(move |file| {
+            opt_directory.and_then(|directory| file.safe_path().map(|path| directory.join(path)))
+        })
+    }
+
     /// Retrieve the bencoded bytes for the `Info` dictionary.
     pub fn to_bytes(&self) -> Vec<u8> {
         // Since there are no file system accesses here, should be fine to unwrap
         InfoBuilder::new()
             .set_private_flag(self.is_private())
+            .set_source_bytes(self.source_bytes())
             // TODO: Revisit this cast...
             .set_piece_length(PieceLength::Custom(self.piece_length() as usize))
             .build(1, self, |_| ())
@@ -250,85 +909,269 @@ impl Accessor for Info {
 }
 
 /// Parses the given info dictionary bytes and builds a Metainfo from them.
-fn parse_info_bytes(bytes: &[u8]) -> ParseResult<Info> {
+fn parse_info_bytes(bytes: &[u8], opt: ParseOpt) -> ParseResult<Info> {
     let info_bencode = try!(BencodeRef::decode(bytes, BDecodeOpt::default()));
 
-    parse_info_dictionary(&info_bencode)
+    parse_info_dictionary(&info_bencode, opt)
 }
 
 /// Parses the given info dictionary and builds an Info from it.
-fn parse_info_dictionary<'a>(info_bencode: &BencodeRef<'a>) -> ParseResult<Info> {
+fn parse_info_dictionary<'a>(info_bencode: &BencodeRef<'a>, opt: ParseOpt) -> ParseResult<Info> {
     let info_hash = InfoHash::from_bytes(info_bencode.buffer());
 
     let info_dict = try!(parse::parse_root_dict(info_bencode));
+    // Parsing requires v1 pieces, so any info dictionary with v2 metadata is a hybrid
+    let is_hybrid = parse::parse_meta_version(info_dict) == Some(2) && parse::parse_file_tree(info_dict).is_some();
+    let info_hash_v2 = if is_hybrid { Some(InfoHash::from_bytes_truncated_sha256(info_bencode.buffer())) } else { None };
+    let is_canonical = is_canonical_bencode(info_bencode);
     let piece_len = try!(parse::parse_piece_length(info_dict));
-    let is_private = parse::parse_private(info_dict);
+    let is_private = parse::parse_private(info_dict, opt.lenient());
+    let source = parse::parse_source_bytes(info_dict).map(|s| s.to_vec());
+    let name = try!(parse_name_lossy(info_dict, opt.lenient()));
+    let name_utf8 = parse::parse_name_utf8(info_dict).map(|n| n.to_owned());
+    // Entries that are not valid info hashes or strings are skipped, since they only serve as hints
+    let similar = parse::parse_similar(info_dict).map(|list| {
+        list.into_iter()
+            .filter_map(|s| parse::parse_similar_bytes(s).ok().and_then(|h| InfoHash::from_hash(h).ok()))
+            .collect()
+    }).unwrap_or(Vec::new());
+    let collections = parse::parse_collections(info_dict).map(|list| {
+        list.into_iter()
+            .filter_map(|c| parse::parse_collection_str(c).ok().map(|c| c.to_owned()))
+            .collect()
+    }).unwrap_or(Vec::new());
+    let field_spans = parse_field_spans(info_bencode.buffer(), info_dict);
+
+    // The pieces field always holds SHA-1 hashes, even for hybrid v1/v2 torrents
+    let hash_kind = HashKind::Sha1;
+    let files = try!(parse_info_files(info_dict, opt));
+    let pieces = span_within(info_bencode.buffer(), try!(parse::parse_pieces(info_dict)));
+    let file_directory = if try!(is_multi_file_torrent(info_dict)) { Some(PathBuf::from(&name)) } else { None };
+
+    Ok(Info {
+        info_hash: info_hash,
+        info_hash_v2: info_hash_v2,
+        is_canonical: is_canonical,
+        info_bytes: info_bencode.buffer().to_vec(),
+        files: files,
+        pieces: pieces,
+        hash_kind: hash_kind,
+        piece_len: piece_len,
+        is_private: is_private,
+        source: source,
+        similar: similar,
+        collections: collections,
+        name: name,
+        name_utf8: name_utf8,
+        field_spans: field_spans,
+        file_directory: file_directory,
+    })
+}
 
-    let pieces = try!(parse::parse_pieces(info_dict));
-    let piece_buffers = try!(allocate_pieces(pieces));
+/// Whether or not the keys of every dictionary, at any depth, appear in sorted order in the source bytes.
+fn is_canonical_bencode(bencode: &BencodeRef) -> bool {
+    match bencode.kind() {
+        BencodeRefKind::List(list) => list.into_iter().all(is_canonical_bencode),
+        BencodeRefKind::Dict(_)    => {
+            let entries = bencode.entries().unwrap_or(Vec::new());
+
+            entries.windows(2).all(|pair| pair[0].0 < pair[1].0) &&
+                entries.iter().all(|&(_, value)| is_canonical_bencode(value))
+        },
+        _ => true
+    }
+}
 
-    if is_multi_file_torrent(info_dict) {
-        let file_directory = try!(parse::parse_name(info_dict));
-        let mut file_directory_path = PathBuf::new();
-        file_directory_path.push(file_directory);
+/// Parses the files of the info dictionary, validating them against the piece hashes.
+///
+/// Must accept the same torrents as `validate_info_files`, which backs `Metainfo::validate_bytes`.
+fn parse_info_files<B>(info_dict: &BDictAccess<B::BKey, B>, opt: ParseOpt) -> ParseResult<Vec<File>>
+    where B: BRefAccess<BType=B> {
+    let (piece_len, num_pieces) = try!(parse_piece_count(info_dict));
 
+    let files = if try!(is_multi_file_torrent(info_dict)) {
         let files_bencode = try!(parse::parse_files_list(info_dict));
 
         let mut files_list = Vec::with_capacity(files_bencode.len());
         for file_bencode in files_bencode {
             let file_dict = try!(parse::parse_file_dict(file_bencode));
-            let file = try!(File::as_multi_file(file_dict));
+            let file = try!(File::as_multi_file(file_dict, opt.lenient()));
 
             files_list.push(file);
         }
 
-        Ok(Info {
-            info_hash: info_hash,
-            files: files_list,
-            pieces: piece_buffers,
-            piece_len: piece_len,
-            is_private: is_private,
-            file_directory: Some(file_directory_path),
-        })
+        if opt.check_duplicate_files() {
+            try!(validate_unique_files(&files_list));
+        }
+
+        files_list
     } else {
-        let file = try!(File::as_single_file(info_dict));
-
-        Ok(Info {
-            info_hash: info_hash,
-            files: vec![file],
-            pieces: piece_buffers,
-            piece_len: piece_len,
-            is_private: is_private,
-            file_directory: None,
-        })
+        vec![try!(File::as_single_file(info_dict, opt.lenient()))]
+    };
+
+    let total_len = try!(files.iter().fold(Ok(0), |acc, file| acc.and_then(|total_len| add_file_length(total_len, file.length()))));
+    if opt.check_piece_count() {
+        try!(validate_piece_count(total_len, piece_len, num_pieces));
     }
-}
 
-/// Returns whether or not this is a multi file torrent.
-fn is_multi_file_torrent<B>(info_dict: &BDictAccess<B::BKey, B>) -> bool
-    where B: BRefAccess {
-    parse::parse_length(info_dict).is_err()
+    Ok(files)
 }
 
-/// Validates and allocates the hash pieces on the heap.
-fn allocate_pieces(pieces: &[u8]) -> ParseResult<Vec<[u8; sha::SHA_HASH_LEN]>> {
-    if pieces.len() % sha::SHA_HASH_LEN != 0 {
-        let error_msg = format!("Piece Hash Length Of {} Is Invalid", pieces.len());
-        Err(ParseError::from_kind(ParseErrorKind::MissingData { details: error_msg }))
-    } else {
-        let mut hash_buffers = Vec::with_capacity(pieces.len() / sha::SHA_HASH_LEN);
-        let mut hash_bytes = [0u8; sha::SHA_HASH_LEN];
+/// Validates the files of the info dictionary with the same checks as `parse_info_files`.
+///
+/// Each file dictionary is checked as it is read, so no `File` is built. Duplicate paths are detected
+/// using path components borrowed from the bencode, so path elements must be valid UTF-8.
+fn validate_info_files<B>(info_dict: &BDictAccess<B::BKey, B>, opt: ParseOpt) -> ParseResult<()>
+    where B: BRefAccess<BType=B> {
+    let (piece_len, num_pieces) = try!(parse_piece_count(info_dict));
 
-        for chunk in pieces.chunks(sha::SHA_HASH_LEN) {
-            for (src, dst) in chunk.iter().zip(hash_bytes.iter_mut()) {
-                *dst = *src;
-            }
+    let total_len = if try!(is_multi_file_torrent(info_dict)) {
+        let mut seen_paths = HashSet::new();
+        let mut total_len = 0;
 
-            hash_buffers.push(hash_bytes);
-        }
+        for file_bencode in try!(parse::parse_files_list(info_dict)) {
+            let file_dict = try!(parse::parse_file_dict(file_bencode));
+            total_len = try!(add_file_length(total_len, try!(parse::parse_length(file_dict))));
 
-        Ok(hash_buffers)
-    }
+            let path_list_bencode = try!(parse::parse_path_list(file_dict));
+            if path_list_bencode.len() == 0 {
+                return Err(ParseError::from_kind(ParseErrorKind::MissingData{ details: "File Path List Is Empty".to_owned() }))
+            }
+
+            // Mirrors how `PathBuf::push` joins the elements, so paths compare the same as in `validate_unique_files`
+            let mut path_components = Vec::with_capacity(path_list_bencode.len());
+            for path_bencode in path_list_bencode {
+                let path = try!(parse::parse_path_str(path_bencode));
+                if path.is_empty() {
+                    return Err(ParseError::from_kind(ParseErrorKind::MissingData{ details: "File Path Contains An Empty Element".to_owned() }))
+                }
+
+                if Path::new(path).has_root() {
+                    path_components.clear();
+                }
+                path_components.extend(Path::new(path).components());
+            }
+
+            let is_padding = parse::parse_attr(file_dict).map_or(false, |attr| attr.contains('p'));
+            if opt.check_duplicate_files() && !is_padding {
+                if seen_paths.contains(&path_components) {
+                    let error_msg = format!("Duplicate File Path {:?} Found", path_components.iter().collect::<PathBuf>());
+                    return Err(ParseError::from_kind(ParseErrorKind::CorruptData { details: error_msg }));
+                }
+                seen_paths.insert(path_components);
+            }
+        }
+
+        total_len
+    } else {
+        try!(parse::parse_length(info_dict))
+    };
+
+    if opt.check_piece_count() {
+        try!(validate_piece_count(total_len, piece_len, num_pieces));
+    }
+
+    Ok(())
+}
+
+/// Parses the piece length and validates the piece hashes, returning the piece length and number of pieces.
+fn parse_piece_count<B>(info_dict: &BDictAccess<B::BKey, B>) -> ParseResult<(u64, usize)>
+    where B: BRefAccess<BType=B> {
+    let piece_len = try!(parse::parse_piece_length(info_dict));
+    let pieces = try!(parse::parse_pieces(info_dict));
+    try!(validate_pieces(pieces, HashKind::Sha1));
+
+    Ok((piece_len, pieces.len() / HashKind::Sha1.len()))
+}
+
+/// Locates the raw bencode for every key in the dictionary, as ranges within the dictionary bytes.
+fn parse_field_spans<'a>(dict_bytes: &[u8], dict: &BDictAccess<&'a [u8], BencodeRef<'a>>) -> BTreeMap<Vec<u8>, Range<usize>> {
+    dict.to_list().into_iter()
+        .map(|(key, value)| (key.to_vec(), span_within(dict_bytes, value.buffer())))
+        .collect()
+}
+
+/// Range that the given slice occupies within the buffer it was sliced from.
+fn span_within(buffer: &[u8], slice: &[u8]) -> Range<usize> {
+    let start = slice.as_ptr() as usize - buffer.as_ptr() as usize;
+
+    start..start + slice.len()
+}
+
+/// Copies out the raw bencode for every key in the dictionary, other than the excluded keys.
+fn parse_raw_fields<'a>(dict: &BDictAccess<&'a [u8], BencodeRef<'a>>, excluded_keys: &[&[u8]]) -> BTreeMap<Vec<u8>, Vec<u8>> {
+    dict.to_list().into_iter()
+        .filter(|&(key, _)| !excluded_keys.contains(key))
+        .map(|(key, value)| (key.to_vec(), value.buffer().to_vec()))
+        .collect()
+}
+
+/// Returns whether or not this is a multi file torrent.
+///
+/// Exactly one of the `length` or `files` keys must be present in the info dictionary.
+fn is_multi_file_torrent<B>(info_dict: &BDictAccess<B::BKey, B>) -> ParseResult<bool>
+    where B: BRefAccess {
+    let has_length = info_dict.contains_key(parse::LENGTH_KEY);
+    let has_files = info_dict.contains_key(parse::FILES_KEY);
+
+    match (has_length, has_files) {
+        (true, false) => Ok(false),
+        (false, true) => Ok(true),
+        (true, true)  => Err(ParseError::from_kind(ParseErrorKind::CorruptData {
+            details: "Both Length And Files Keys Found In Info Dictionary".to_owned()
+        })),
+        (false, false) => Err(ParseError::from_kind(ParseErrorKind::CorruptData {
+            details: "Neither Length Nor Files Keys Found In Info Dictionary".to_owned()
+        }))
+    }
+}
+
+/// Validates that no two files within a multi file torrent share the same path.
+///
+/// Padding files are skipped, since BEP-47 padding files of equal length commonly share a path.
+fn validate_unique_files(files: &[File]) -> ParseResult<()> {
+    let mut seen_paths = HashSet::with_capacity(files.len());
+
+    for file in files.iter().filter(|file| !file.is_padding()) {
+        if !seen_paths.insert(file.path()) {
+            let error_msg = format!("Duplicate File Path {:?} Found", file.path());
+            return Err(ParseError::from_kind(ParseErrorKind::CorruptData { details: error_msg }));
+        }
+    }
+
+    Ok(())
+}
+
+/// Adds the length of a file to the running total, validating that the total fits within a u64.
+fn add_file_length(total_len: u64, length: u64) -> ParseResult<u64> {
+    total_len.checked_add(length)
+        .ok_or_else(|| ParseError::from_kind(ParseErrorKind::CorruptData{ details: "Total File Length Overflows".to_owned() }))
+}
+
+/// Validates that the number of piece hashes covers the total length of the files.
+fn validate_piece_count(total_len: u64, piece_len: u64, num_pieces: usize) -> ParseResult<()> {
+    if piece_len == 0 {
+        return Err(ParseError::from_kind(ParseErrorKind::CorruptData{ details: "Piece Length Of 0 Is Invalid".to_owned() }))
+    }
+
+    let expected_pieces = total_len / piece_len + if total_len % piece_len != 0 { 1 } else { 0 };
+
+    if expected_pieces != num_pieces as u64 {
+        let error_msg = format!("Expected {} Piece Hashes For Total Length {} But Found {}", expected_pieces, total_len, num_pieces);
+        Err(ParseError::from_kind(ParseErrorKind::MissingData{ details: error_msg }))
+    } else {
+        Ok(())
+    }
+}
+
+/// Validates that the hash pieces are made up of whole hashes.
+fn validate_pieces(pieces: &[u8], hash_kind: HashKind) -> ParseResult<()> {
+    if pieces.len() % hash_kind.len() != 0 {
+        let error_msg = format!("Piece Hash Length Of {} Is Invalid", pieces.len());
+        Err(ParseError::from_kind(ParseErrorKind::MissingData { details: error_msg }))
+    } else {
+        Ok(())
+    }
 }
 
 // ----------------------------------------------------------------------------//
@@ -336,45 +1179,100 @@ fn allocate_pieces(pieces: &[u8]) -> ParseResult<Vec<[u8; sha::SHA_HASH_LEN]>> {
 /// Contains information for a single file.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct File {
-    len:    u64,
-    path:   PathBuf,
-    md5sum: Option<Vec<u8>>,
+    len:          u64,
+    path:         PathBuf,
+    // Path elements as found in the torrent file, which may not be UTF-8.
+    raw_path:     Vec<Vec<u8>>,
+    md5sum:       Option<Vec<u8>>,
+    attr:         Option<String>,
+    sha1:         Option<[u8; sha::SHA_HASH_LEN]>,
+    symlink_path: Option<PathBuf>,
+    // Whether every original path element was safe to join.
+    is_safe:      bool,
 }
 
 impl File {
     /// Parse the info dictionary and generate a single file File.
-    fn as_single_file<B>(info_dict: &BDictAccess<B::BKey, B>) -> ParseResult<File>
-        where B: BRefAccess {
+    fn as_single_file<B>(info_dict: &BDictAccess<B::BKey, B>, lenient: bool) -> ParseResult<File>
+        where B: BRefAccess<BType=B> {
         let length = try!(parse::parse_length(info_dict));
         let md5sum = parse::parse_md5sum(info_dict).map(|m| m.to_owned());
-        let name = try!(parse::parse_name(info_dict));
+        let attr = parse::parse_attr(info_dict).map(|a| a.to_owned());
+        let sha1 = parse::parse_sha1(info_dict).and_then(|s| ShaHash::from_hash(s).ok()).map(|s| s.into());
+        let symlink_path = File::parse_symlink_path(info_dict);
+        let name = try!(parse_name_lossy(info_dict, lenient));
+        let raw_name = parse::parse_name_bytes(info_dict).map(|n| n.to_vec()).unwrap_or_else(|_| name.clone().into_bytes());
 
         Ok(File {
             len: length,
-            path: name.to_owned().into(),
+            is_safe: is_safe_path_element(&name),
+            path: name.into(),
+            raw_path: vec![raw_name],
             md5sum: md5sum,
+            attr: attr,
+            sha1: sha1,
+            symlink_path: symlink_path,
         })
     }
 
     /// Parse the file dictionary and generate a multi file File.
-    fn as_multi_file<B>(file_dict: &BDictAccess<B::BKey, B>) -> ParseResult<File>
+    fn as_multi_file<B>(file_dict: &BDictAccess<B::BKey, B>, lenient: bool) -> ParseResult<File>
         where B: BRefAccess<BType=B> {
         let length = try!(parse::parse_length(file_dict));
         let md5sum = parse::parse_md5sum(file_dict).map(|m| m.to_owned());
+        let attr = parse::parse_attr(file_dict).map(|a| a.to_owned());
+        let sha1 = parse::parse_sha1(file_dict).and_then(|s| ShaHash::from_hash(s).ok()).map(|s| s.into());
+        let symlink_path = File::parse_symlink_path(file_dict);
 
         let path_list_bencode = try!(parse::parse_path_list(file_dict));
+        if path_list_bencode.len() == 0 {
+            return Err(ParseError::from_kind(ParseErrorKind::MissingData{ details: "File Path List Is Empty".to_owned() }))
+        }
 
         let mut path_buf = PathBuf::new();
+        let mut raw_path = Vec::with_capacity(path_list_bencode.len());
+        let mut is_safe = true;
         for path_bencode in path_list_bencode {
-            let path = try!(parse::parse_path_str(path_bencode));
+            let raw = try!(parse::parse_path_bytes(path_bencode));
+            let path = if lenient {
+                String::from_utf8_lossy(raw).into_owned()
+            } else {
+                try!(parse::parse_path_str(path_bencode)).to_owned()
+            };
+            if path.is_empty() {
+                return Err(ParseError::from_kind(ParseErrorKind::MissingData{ details: "File Path Contains An Empty Element".to_owned() }))
+            }
 
+            is_safe = is_safe && is_safe_path_element(&path);
             path_buf.push(path);
+            raw_path.push(raw.to_vec());
         }
 
         Ok(File {
             len: length,
             path: path_buf,
+            raw_path: raw_path,
             md5sum: md5sum,
+            attr: attr,
+            sha1: sha1,
+            symlink_path: symlink_path,
+            is_safe: is_safe,
+        })
+    }
+
+    /// Parse the symlink path from the info or file dictionary, if present and valid.
+    fn parse_symlink_path<B>(info_or_file_dict: &BDictAccess<B::BKey, B>) -> Option<PathBuf>
+        where B: BRefAccess<BType=B> {
+        parse::parse_symlink_path_list(info_or_file_dict).and_then(|path_list_bencode| {
+            let mut path_buf = PathBuf::new();
+            for path_bencode in path_list_bencode {
+                match parse::parse_path_str(path_bencode) {
+                    Ok(path) if is_safe_path_element(path) => path_buf.push(path),
+                    _ => return None
+                }
+            }
+
+            Some(path_buf)
         })
     }
 
@@ -394,18 +1292,148 @@ impl File {
     pub fn path(&self) -> &Path {
         &self.path
     }
+
+    /// Elements of the path of the file, decoded from the given character encoding.
+    ///
+    /// Pass `Metainfo::encoding` to correct the path of torrents created with a legacy charset,
+    /// such as Shift-JIS. Decoding from anything other than UTF-8 requires the `encoding` feature;
+    /// otherwise, or if the encoding is unknown, invalid UTF-8 is replaced lossily.
+    pub fn decoded_paths(&self, encoding: Option<&str>) -> Vec<String> {
+        self.raw_path.iter().map(|raw| decode_string(raw, encoding)).collect()
+    }
+
+    /// Attributes of the file (BEP-47).
+    ///
+    /// Each character is a separate attribute, for example `x` for executable, `h` for hidden,
+    /// `l` for symlink, and `p` for padding.
+    pub fn attributes(&self) -> Option<&str> {
+        self.attr.as_ref().map(|a| &a[..])
+    }
+
+    /// Whether or not this is a padding file (BEP-47).
+    ///
+    /// Padding files only exist to align the next file to a piece boundary, and should not be written to disk.
+    pub fn is_padding(&self) -> bool {
+        self.attributes().map_or(false, |attr| attr.contains('p'))
+    }
+
+    /// Target of the symlink, relative to the torrent root, if this file is a symlink (BEP-47).
+    ///
+    /// Returns None if any element of the target is not safe, using the same checks as `File::safe_path`.
+    pub fn symlink_target(&self) -> Option<&Path> {
+        self.symlink_path.as_ref().map(|s| s.as_ref())
+    }
+
+    /// Optional sha1 of the file (BEP-47).
+    ///
+    /// A sha1 that is not exactly 20 bytes long is ignored when parsing.
+    pub fn sha1(&self) -> Option<&[u8; sha::SHA_HASH_LEN]> {
+        self.sha1.as_ref()
+    }
+
+    /// Check the file contents read from the given reader against the sha1 of the file (BEP-47).
+    ///
+    /// Returns None if the file has no sha1. Contents that are not exactly `File::length` bytes
+    /// long do not match. The reader is streamed through, so the file is never fully buffered.
+    pub fn verify_contents<R>(&self, mut reader: R) -> io::Result<Option<bool>>
+        where R: Read {
+        let expected = match self.sha1 {
+            Some(ref expected) => expected,
+            None               => return Ok(None)
+        };
+
+        let mut buffer = [0u8; 16 * 1024];
+        let mut builder = ShaHashBuilder::new();
+        let mut total_len = 0u64;
+        loop {
+            let read_len = match reader.read(&mut buffer) {
+                Ok(0)        => break,
+                Ok(read_len) => read_len,
+                Err(error)   => if error.kind() == io::ErrorKind::Interrupted { continue } else { return Err(error) }
+            };
+
+            builder = builder.add_bytes(&buffer[..read_len]);
+            total_len += read_len as u64;
+        }
+
+        Ok(Some(total_len == self.len && builder.build() == ShaHash::from(*expected)))
+    }
+
+    /// Path of the file, if it is safe to join with a download directory.
+    ///
+    /// Returns None if any element of the path, as found in the torrent file, is empty, is `.` or
+    /// `..`, contains a path separator or null byte, or would otherwise make the path absolute.
+    pub fn safe_path(&self) -> Option<&Path> {
+        if self.is_safe && !self.path.as_os_str().is_empty() {
+            Some(&self.path)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses the name from the info dictionary, if lenient then invalid UTF-8 is replaced lossily.
+///
+/// If the name is missing or is not valid UTF-8 (when not lenient), a valid name.utf-8 is used instead.
+fn parse_name_lossy<B>(info_dict: &BDictAccess<B::BKey, B>, lenient: bool) -> ParseResult<String>
+    where B: BRefAccess {
+    let opt_name_utf8 = parse::parse_name_utf8(info_dict);
+    // The name key is only required when there is no valid name.utf-8 to fall back on
+    let name = match (parse::parse_name_bytes(info_dict), opt_name_utf8) {
+        (Ok(name), _)             => name,
+        (Err(_), Some(name_utf8)) => return Ok(name_utf8.to_owned()),
+        (Err(error), None)        => return Err(error)
+    };
+
+    match (str::from_utf8(name), opt_name_utf8) {
+        (Ok(name), _)             => Ok(name.to_owned()),
+        (Err(_), _) if lenient    => Ok(String::from_utf8_lossy(name).into_owned()),
+        (Err(_), Some(name_utf8)) => Ok(name_utf8.to_owned()),
+        (Err(_), None)            => parse::parse_name(info_dict).map(|name| name.to_owned())
+    }
+}
+
+/// Decode the given bytes using the named character encoding, falling back to lossy UTF-8.
+#[cfg(feature = "encoding")]
+fn decode_string(bytes: &[u8], encoding: Option<&str>) -> String {
+    match encoding.and_then(|label| Encoding::for_label(label.as_bytes())) {
+        Some(encoding) => encoding.decode_without_bom_handling(bytes).0.into_owned(),
+        None           => String::from_utf8_lossy(bytes).into_owned()
+    }
+}
+
+/// Decode the given bytes using the named character encoding, falling back to lossy UTF-8.
+#[cfg(not(feature = "encoding"))]
+fn decode_string(bytes: &[u8], _encoding: Option<&str>) -> String {
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+/// Returns whether or not the given path element is safe to push onto a path.
+fn is_safe_path_element(element: &str) -> bool {
+    let has_unsafe_char = element.chars().any(|c| c == '/' || c == '\\' || c == '\0');
+    // Catches `.`, `..`, as well as root and prefix components
+    let has_special_component = Path::new(element).components().any(|component| match component {
+        Component::Normal(_) => false,
+        _ => true
+    });
+
+    !element.is_empty() && !has_unsafe_char && !has_special_component
 }
 
 #[cfg(test)]
 mod tests {
+    use std::io::{self, Cursor};
+    use std::ops::Range;
     use std::path::{Path, PathBuf};
 
-    use bip_bencode::{BencodeMut, BMutAccess};
-    use bip_util::sha;
+    use bip_bencode::{BencodeMut, BencodeRef, BDecodeOpt, BMutAccess};
+    use bip_util::sha::{self, ShaHash};
     use bip_util::bt::InfoHash;
 
-    use metainfo::Metainfo;
+    use error::ParseErrorKind;
+    use metainfo::{self, File, HashKind, Info, Metainfo, SHA256_HASH_LEN};
     use parse;
+    use parse_opt::ParseOpt;
 
     /// Helper function for manually constructing a metainfo file based on the parameters given.
     ///
@@ -909,4 +1937,1388 @@ mod tests {
                                    None,
                                    Some(vec![(Some(file_len), None, None)]));
     }
+
+    /// Helper function for building a minimal single file info dictionary.
+    fn dummy_single_file_info() -> BencodeMut<'static> {
+        ben_map!{
+            parse::NAME_KEY         => ben_bytes!("dummy_file_name"),
+            parse::LENGTH_KEY       => ben_int!(0),
+            parse::PIECE_LENGTH_KEY => ben_int!(1024),
+            parse::PIECES_KEY       => ben_bytes!(&[0u8; sha::SHA_HASH_LEN][..])
+        }
+    }
+
+    /// Helper function for building the bytes of a multi file info dictionary with the given file paths.
+    fn multi_file_info_bytes(file_paths: &[&[&str]]) -> Vec<u8> {
+        let files: Vec<_> = file_paths.iter().map(|path| (*path, 0)).collect();
+
+        multi_file_info_bytes_with_lengths(&files)
+    }
+
+    /// Helper function for building the bytes of a multi file info dictionary with the given file paths and lengths.
+    fn multi_file_info_bytes_with_lengths(files: &[(&[&str], i64)]) -> Vec<u8> {
+        let mut bencode_files = BencodeMut::new_list();
+
+        {
+            let bencode_files_access = bencode_files.list_mut().unwrap();
+
+            for &(path, length) in files.iter() {
+                let mut bencode_path = BencodeMut::new_list();
+
+                {
+                    let bencode_path_access = bencode_path.list_mut().unwrap();
+                    for path_element in path.iter() {
+                        bencode_path_access.push(ben_bytes!(*path_element));
+                    }
+                }
+
+                bencode_files_access.push(ben_map!{
+                    parse::LENGTH_KEY => ben_int!(length),
+                    parse::PATH_KEY   => bencode_path
+                });
+            }
+        }
+
+        (ben_map!{
+            parse::NAME_KEY         => ben_bytes!("dummy_file_directory"),
+            parse::PIECE_LENGTH_KEY => ben_int!(1024),
+            parse::PIECES_KEY       => ben_bytes!(&[0u8; sha::SHA_HASH_LEN][..]),
+            parse::FILES_KEY        => bencode_files
+        }).encode()
+    }
+
+    #[test]
+    fn positive_parse_distinct_file_paths() {
+        let info_bytes = multi_file_info_bytes(&[&["dummy_sub_directory", "dummy_file_name"],
+                                                 &["dummy_file_name"]]);
+
+        let info = Info::from_bytes(info_bytes).unwrap();
+        assert_eq!(info.files().count(), 2);
+    }
+
+    #[test]
+    fn positive_parse_duplicate_file_paths_unchecked() {
+        let info_bytes = multi_file_info_bytes(&[&["dummy_sub_directory", "dummy_file_name"],
+                                                 &["dummy_sub_directory", "dummy_file_name"]]);
+
+        let opt = ParseOpt::new().set_check_duplicate_files(false);
+        let info = Info::from_bytes_with_opt(info_bytes, opt).unwrap();
+        assert_eq!(info.files().count(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn negative_parse_duplicate_file_paths() {
+        let info_bytes = multi_file_info_bytes(&[&["dummy_sub_directory", "dummy_file_name"],
+                                                 &["dummy_sub_directory", "dummy_file_name"]]);
+
+        Info::from_bytes(info_bytes).unwrap();
+    }
+
+    #[test]
+    fn positive_parse_source_round_trip() {
+        let mut info = dummy_single_file_info();
+        info.dict_mut().unwrap().insert(parse::SOURCE_KEY.into(), ben_bytes!("DUMMY"));
+        let info_bytes = info.encode();
+
+        let info = Info::from_bytes(&info_bytes).unwrap();
+        assert_eq!(info.source(), Some("DUMMY"));
+        assert_eq!(info.info_hash(), InfoHash::from_bytes(&info_bytes));
+
+        let metainfo = Metainfo::from(info.clone());
+        let round_trip = Metainfo::from_bytes(metainfo.to_bytes()).unwrap();
+        assert_eq!(round_trip.info().source(), Some("DUMMY"));
+        assert_eq!(round_trip.info().info_hash(), info.info_hash());
+    }
+
+    /// Info dictionary bytes for a multi file torrent with a single, non UTF-8, path element.
+    fn latin1_path_info_bytes() -> Vec<u8> {
+        (ben_map!{
+            parse::NAME_KEY         => ben_bytes!("dummy_file_directory"),
+            parse::PIECE_LENGTH_KEY => ben_int!(1024),
+            parse::PIECES_KEY       => ben_bytes!(&[0u8; sha::SHA_HASH_LEN][..]),
+            parse::FILES_KEY        => ben_list!(ben_map!{
+                parse::LENGTH_KEY => ben_int!(0),
+                parse::PATH_KEY   => ben_list!(ben_bytes!(&b"caf\xE9"[..]))
+            })
+        }).encode()
+    }
+
+    #[test]
+    fn negative_parse_non_utf8_path() {
+        assert!(Info::from_bytes(latin1_path_info_bytes()).is_err());
+    }
+
+    #[test]
+    fn positive_parse_non_utf8_path_lenient() {
+        let info = Info::from_bytes_with_opt(latin1_path_info_bytes(), ParseOpt::new().set_lenient(true)).unwrap();
+        let file = info.files().next().unwrap();
+
+        assert_eq!(Path::new("caf\u{FFFD}"), file.path());
+        assert_eq!(vec!["caf\u{FFFD}".to_owned()], file.decoded_paths(None));
+        assert_eq!(vec!["caf\u{FFFD}".to_owned()], file.decoded_paths(Some("unknown-encoding")));
+    }
+
+    #[test]
+    #[cfg(feature = "encoding")]
+    fn positive_decoded_paths_with_encoding() {
+        let info = Info::from_bytes_with_opt(latin1_path_info_bytes(), ParseOpt::new().set_lenient(true)).unwrap();
+        let file = info.files().next().unwrap();
+
+        assert_eq!(vec!["caf\u{E9}".to_owned()], file.decoded_paths(Some("ISO-8859-1")));
+    }
+
+    #[test]
+    fn positive_parse_similar_and_collections() {
+        let similar_hash = [1u8; sha::SHA_HASH_LEN];
+
+        let mut info = dummy_single_file_info();
+        info.dict_mut().unwrap().insert(parse::SIMILAR_KEY.into(), ben_list!(
+            ben_bytes!(&similar_hash[..]),
+            ben_bytes!(&b"short"[..])
+        ));
+        info.dict_mut().unwrap().insert(parse::COLLECTIONS_KEY.into(), ben_list!(
+            ben_bytes!("dummy_collection"),
+            ben_int!(5)
+        ));
+
+        let info = Info::from_bytes(info.encode()).unwrap();
+        assert_eq!(info.similar_torrents(), &[InfoHash::from(similar_hash)]);
+        assert_eq!(info.collections(), &["dummy_collection".to_owned()]);
+        assert!(info.extra_field(parse::SIMILAR_KEY).is_some());
+    }
+
+    #[test]
+    fn positive_parse_no_similar_or_collections() {
+        let info = Info::from_bytes(dummy_single_file_info().encode()).unwrap();
+
+        assert!(info.similar_torrents().is_empty());
+        assert!(info.collections().is_empty());
+    }
+
+    #[test]
+    fn positive_parse_source_non_utf8_round_trip() {
+        let mut info = dummy_single_file_info();
+        info.dict_mut().unwrap().insert(parse::SOURCE_KEY.into(), ben_bytes!(&b"DUMMY\xFF"[..]));
+        let info_bytes = info.encode();
+
+        let info = Info::from_bytes(&info_bytes).unwrap();
+        assert_eq!(None, info.source());
+        assert_eq!(Some(&b"DUMMY\xFF"[..]), info.source_bytes());
+
+        let round_trip = Metainfo::from_bytes(Metainfo::from(info.clone()).to_bytes()).unwrap();
+        assert_eq!(Some(&b"DUMMY\xFF"[..]), round_trip.info().source_bytes());
+        assert_eq!(InfoHash::from_bytes(&info_bytes), round_trip.info().info_hash());
+    }
+
+    #[test]
+    fn positive_parse_http_seeds_skips_invalid() {
+        let info = dummy_single_file_info();
+        let root_bytes = (ben_map!{
+            parse::INFO_KEY       => info,
+            parse::HTTP_SEEDS_KEY => ben_list!(
+                ben_bytes!("http://dummy_domain.com/seed"),
+                ben_bytes!(&b"\xFF\xFE"[..]),
+                ben_int!(5),
+                ben_bytes!("udp://dummy_domain.com:8989"),
+                ben_bytes!("https://dummy_domain.com/seed")
+            )
+        }).encode();
+
+        let metainfo = Metainfo::from_bytes(root_bytes).unwrap();
+        assert_eq!(metainfo.http_seeds(), &["http://dummy_domain.com/seed".to_owned(),
+                                            "https://dummy_domain.com/seed".to_owned()][..]);
+    }
+
+    #[test]
+    fn positive_parse_no_http_seeds() {
+        let info = dummy_single_file_info();
+        let root_bytes = (ben_map!{ parse::INFO_KEY => info }).encode();
+
+        let metainfo = Metainfo::from_bytes(root_bytes).unwrap();
+        assert!(metainfo.http_seeds().is_empty());
+    }
+
+    #[test]
+    fn positive_files_larger_than() {
+        let info = multi_file_info_bytes_with_lengths(&[(&["dummy_file_zero"], 0), (&["dummy_file_one"], 5)]);
+
+        let info = Info::from_bytes(info).unwrap();
+        let larger_files: Vec<_> = info.files_larger_than(0).map(|file| file.path().to_owned()).collect();
+        assert_eq!(larger_files, vec![PathBuf::from("dummy_file_one")]);
+    }
+
+    #[test]
+    fn positive_files_pieces_exact_size_double_ended() {
+        let info_bytes = multi_file_info_bytes(&[&["dummy_file_zero"], &["dummy_file_one"]]);
+        let info = Info::from_bytes(info_bytes).unwrap();
+
+        assert_eq!(info.files().len(), 2);
+        assert_eq!(info.files().rev().next().unwrap().path(), Path::new("dummy_file_one"));
+
+        let mut pieces = info.pieces();
+        assert_eq!(pieces.len(), 1);
+        assert!(pieces.next_back().is_some());
+        assert_eq!(pieces.len(), 0);
+    }
+
+    #[test]
+    fn positive_files_pieces_clone_independent() {
+        let info_bytes = multi_file_info_bytes(&[&["dummy_file_zero"], &["dummy_file_one"]]);
+        let info = Info::from_bytes(info_bytes).unwrap();
+
+        let mut files = info.files();
+        files.next();
+        let cloned_files = files.clone();
+        files.next();
+        assert_eq!(files.len(), 0);
+        assert_eq!(cloned_files.len(), 1);
+
+        let pieces = info.pieces();
+        assert_eq!(pieces.clone().count(), pieces.len());
+    }
+
+    #[test]
+    fn positive_is_multi_file() {
+        let single_info = Info::from_bytes(dummy_single_file_info().encode()).unwrap();
+        assert!(!single_info.is_multi_file());
+
+        let multi_info = Info::from_bytes(multi_file_info_bytes(&[&["dummy_file_zero"]])).unwrap();
+        assert!(multi_info.is_multi_file());
+    }
+
+    #[test]
+    fn negative_length_and_files_present() {
+        let mut info_bencode = dummy_single_file_info();
+        info_bencode.dict_mut().unwrap().insert(parse::FILES_KEY.into(), ben_list!());
+
+        match Info::from_bytes(info_bencode.encode()).unwrap_err().kind() {
+            &ParseErrorKind::CorruptData { .. } => (),
+            other => panic!("Unexpected Error Kind {:?}", other)
+        }
+    }
+
+    #[test]
+    fn negative_length_and_files_missing() {
+        let mut info_bencode = dummy_single_file_info();
+        info_bencode.dict_mut().unwrap().remove(parse::LENGTH_KEY);
+
+        match Info::from_bytes(info_bencode.encode()).unwrap_err().kind() {
+            &ParseErrorKind::CorruptData { .. } => (),
+            other => panic!("Unexpected Error Kind {:?}", other)
+        }
+    }
+
+    #[test]
+    fn positive_verify_piece() {
+        let piece_data = b"dummy_piece_data";
+        let piece_hash: [u8; sha::SHA_HASH_LEN] = ShaHash::from_bytes(&piece_data[..]).into();
+
+        let mut info_bencode = dummy_single_file_info();
+        info_bencode.dict_mut().unwrap().insert(parse::PIECES_KEY.into(), ben_bytes!(&piece_hash[..]));
+        let info = Info::from_bytes(info_bencode.encode()).unwrap();
+
+        assert_eq!(Some(true), info.verify_piece(0, &piece_data[..]));
+        assert_eq!(Some(false), info.verify_piece(0, b"corrupt_piece_data"));
+        assert_eq!(None, info.verify_piece(1, &piece_data[..]));
+    }
+
+    #[test]
+    fn positive_private_bytes_lenient() {
+        let mut info_bencode = dummy_single_file_info();
+        info_bencode.dict_mut().unwrap().insert(parse::PRIVATE_KEY.into(), ben_bytes!("1"));
+        let info_bytes = info_bencode.encode();
+
+        let info = Info::from_bytes_with_opt(&info_bytes[..], ParseOpt::new().set_lenient(true)).unwrap();
+        assert_eq!(Some(true), info.is_private());
+    }
+
+    #[test]
+    fn positive_private_bytes_strict() {
+        let mut info_bencode = dummy_single_file_info();
+        info_bencode.dict_mut().unwrap().insert(parse::PRIVATE_KEY.into(), ben_bytes!("1"));
+        let info_bytes = info_bencode.encode();
+
+        let info = Info::from_bytes(&info_bytes[..]).unwrap();
+        assert_eq!(None, info.is_private());
+    }
+
+    #[test]
+    fn positive_info_extra_field() {
+        let mut info_bencode = dummy_single_file_info();
+        info_bencode.dict_mut().unwrap().insert((&b"x_cross_seed"[..]).into(), ben_bytes!("dummy_cross_seed"));
+        let info = Info::from_bytes(info_bencode.encode()).unwrap();
+
+        assert_eq!(Some(&b"16:dummy_cross_seed"[..]), info.extra_field(b"x_cross_seed"));
+        assert_eq!(Some(&b"15:dummy_file_name"[..]), info.extra_field(parse::NAME_KEY));
+        assert_eq!(Some(&b"i0e"[..]), info.extra_field(parse::LENGTH_KEY));
+        assert_eq!(Some(&ben_bytes!(&[0u8; sha::SHA_HASH_LEN][..]).encode()[..]), info.extra_field(parse::PIECES_KEY));
+        assert!(info.extra_field(b"missing").is_none());
+    }
+
+    #[test]
+    fn positive_metainfo_extra_field() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY          => dummy_single_file_info(),
+            parse::CREATED_BY_KEY    => ben_bytes!("dummy_client"),
+            parse::COMMENT_UTF8_KEY  => ben_bytes!("dummy_comment"),
+            "publisher-url"          => ben_bytes!("http://dummy_domain.com")
+        }).encode();
+        let metainfo = Metainfo::from_bytes(metainfo_bytes).unwrap();
+
+        assert_eq!(Some(&b"23:http://dummy_domain.com"[..]), metainfo.extra_field(b"publisher-url"));
+        assert_eq!(Some(&b"12:dummy_client"[..]), metainfo.extra_field(parse::CREATED_BY_KEY));
+        assert_eq!(Some(&b"13:dummy_comment"[..]), metainfo.extra_field(parse::COMMENT_UTF8_KEY));
+        assert!(metainfo.extra_field(parse::INFO_KEY).is_none());
+        assert!(metainfo.extra_field(b"missing").is_none());
+    }
+
+    #[test]
+    fn positive_name_and_comment_utf8() {
+        let mut info_bencode = dummy_single_file_info();
+        info_bencode.dict_mut().unwrap().insert(parse::NAME_UTF8_KEY.into(), ben_bytes!("dummy_file_name_utf8"));
+
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY         => info_bencode,
+            parse::COMMENT_KEY      => ben_bytes!("dummy_comment"),
+            parse::COMMENT_UTF8_KEY => ben_bytes!("dummy_comment_utf8")
+        }).encode();
+        let metainfo = Metainfo::from_bytes(metainfo_bytes).unwrap();
+
+        assert_eq!(Some("dummy_comment_utf8"), metainfo.comment_utf8());
+        assert_eq!(Some("dummy_comment"), metainfo.comment());
+        assert_eq!("dummy_file_name_utf8", metainfo.info().name_utf8());
+    }
+
+    #[test]
+    fn positive_parse_non_utf8_name_with_name_utf8() {
+        let mut info_bencode = dummy_single_file_info();
+        {
+            let info_dict = info_bencode.dict_mut().unwrap();
+            info_dict.insert(parse::NAME_KEY.into(), ben_bytes!(&b"dummy_file_name\xFF"[..]));
+            info_dict.insert(parse::NAME_UTF8_KEY.into(), ben_bytes!("dummy_file_name_utf8"));
+        }
+
+        let info = Info::from_bytes(info_bencode.encode()).unwrap();
+        assert_eq!("dummy_file_name_utf8", info.name_utf8());
+        assert_eq!(Path::new("dummy_file_name_utf8"), info.files().next().unwrap().path());
+    }
+
+    #[test]
+    fn positive_parse_missing_name_with_name_utf8() {
+        let mut info_bencode = dummy_single_file_info();
+        {
+            let info_dict = info_bencode.dict_mut().unwrap();
+            info_dict.remove(parse::NAME_KEY);
+            info_dict.insert(parse::NAME_UTF8_KEY.into(), ben_bytes!("dummy_file_name_utf8"));
+        }
+
+        let info = Info::from_bytes(info_bencode.encode()).unwrap();
+        assert_eq!(Path::new("dummy_file_name_utf8"), info.files().next().unwrap().path());
+    }
+
+    #[test]
+    fn negative_parse_non_utf8_name_without_name_utf8() {
+        let mut info_bencode = dummy_single_file_info();
+        info_bencode.dict_mut().unwrap().insert(parse::NAME_KEY.into(), ben_bytes!(&b"dummy_file_name\xFF"[..]));
+
+        assert!(Info::from_bytes(info_bencode.encode()).is_err());
+    }
+
+    #[test]
+    fn positive_name_and_comment_utf8_fallback() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY    => dummy_single_file_info(),
+            parse::COMMENT_KEY => ben_bytes!("dummy_comment")
+        }).encode();
+        let metainfo = Metainfo::from_bytes(metainfo_bytes).unwrap();
+
+        assert_eq!(Some("dummy_comment"), metainfo.comment_utf8());
+        assert_eq!("dummy_file_name", metainfo.info().name_utf8());
+    }
+
+    #[test]
+    fn positive_full_paths_multi_file() {
+        let info_bytes = multi_file_info_bytes(&[&["dummy_dir", "dummy_file_zero"], &["dummy_file_one"]]);
+        let info = Info::from_bytes(info_bytes).unwrap();
+
+        let full_paths: Vec<Option<PathBuf>> = info.full_paths().collect();
+        assert_eq!(vec![Some(PathBuf::from("dummy_file_directory/dummy_dir/dummy_file_zero")),
+                        Some(PathBuf::from("dummy_file_directory/dummy_file_one"))], full_paths);
+    }
+
+    #[test]
+    fn positive_full_paths_single_file() {
+        let info = Info::from_bytes(dummy_single_file_info().encode()).unwrap();
+
+        let full_paths: Vec<Option<PathBuf>> = info.full_paths().collect();
+        assert_eq!(vec![Some(PathBuf::from("dummy_file_name"))], full_paths);
+    }
+
+    #[test]
+    fn positive_full_paths_skips_traversal() {
+        let info_bytes = multi_file_info_bytes(&[&["..", "..", "etc", "passwd"], &["/dummy_file_one"], &["dummy_file_two"]]);
+        let info = Info::from_bytes(info_bytes).unwrap();
+
+        let full_paths: Vec<Option<PathBuf>> = info.full_paths().collect();
+        assert_eq!(vec![None, None, Some(PathBuf::from("dummy_file_directory/dummy_file_two"))], full_paths);
+    }
+
+    #[test]
+    fn positive_full_paths_skips_unsafe_directory() {
+        let info_bytes = (ben_map!{
+            parse::NAME_KEY         => ben_bytes!(".."),
+            parse::PIECE_LENGTH_KEY => ben_int!(1024),
+            parse::PIECES_KEY       => ben_bytes!(&[0u8; sha::SHA_HASH_LEN][..]),
+            parse::FILES_KEY        => ben_list!(ben_map!{
+                parse::LENGTH_KEY => ben_int!(0),
+                parse::PATH_KEY   => ben_list!(ben_bytes!("dummy_file_zero"))
+            })
+        }).encode();
+        let info = Info::from_bytes(info_bytes).unwrap();
+
+        let full_paths: Vec<Option<PathBuf>> = info.full_paths().collect();
+        assert_eq!(vec![None], full_paths);
+    }
+
+    #[test]
+    fn positive_safe_path() {
+        let info_bytes = multi_file_info_bytes(&[&["dummy_dir", "dummy_file_zero"]]);
+        let info = Info::from_bytes(info_bytes).unwrap();
+
+        let file = info.files().next().unwrap();
+        assert_eq!(Some(Path::new("dummy_dir/dummy_file_zero")), file.safe_path());
+    }
+
+    #[test]
+    fn negative_safe_path_unsafe_elements() {
+        let unsafe_paths: &[&[&str]] = &[&["..", "dummy_file"], &["."], &["/dummy_file"],
+                                         &["dummy_dir/dummy_file"], &["dummy_dir\\dummy_file"], &["dummy\0file"]];
+
+        for unsafe_path in unsafe_paths.iter() {
+            let info = Info::from_bytes(multi_file_info_bytes(&[unsafe_path])).unwrap();
+
+            assert!(info.files().next().unwrap().safe_path().is_none(), "{:?} Was Safe", unsafe_path);
+        }
+    }
+
+    #[test]
+    fn positive_creation_date_bytes_lenient() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY          => dummy_single_file_info(),
+            parse::CREATION_DATE_KEY => ben_bytes!("1500000000.75")
+        }).encode();
+
+        let metainfo = Metainfo::from_bytes_with_opt(&metainfo_bytes[..], ParseOpt::new().set_lenient(true)).unwrap();
+        assert_eq!(Some(1500000000), metainfo.creation_date());
+        assert_eq!(Some(&b"13:1500000000.75"[..]), metainfo.creation_date_raw());
+    }
+
+    #[test]
+    fn positive_creation_date_bytes_strict() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY          => dummy_single_file_info(),
+            parse::CREATION_DATE_KEY => ben_bytes!("1500000000")
+        }).encode();
+
+        let metainfo = Metainfo::from_bytes(&metainfo_bytes[..]).unwrap();
+        assert_eq!(None, metainfo.creation_date());
+        assert_eq!(Some(&b"10:1500000000"[..]), metainfo.creation_date_raw());
+    }
+
+    #[test]
+    fn positive_compute_info_hash() {
+        let info = dummy_single_file_info();
+        let info_bytes = info.encode();
+
+        assert_eq!(InfoHash::from_bytes(&info_bytes), metainfo::compute_info_hash(&info));
+        assert_eq!(Info::from_bytes(&info_bytes).unwrap().info_hash(), metainfo::compute_info_hash(&info));
+    }
+
+    #[test]
+    fn positive_compute_info_hash_unsorted_keys() {
+        let info_bytes = b"d4:name15:dummy_file_name6:lengthi0e12:piece lengthi1024e6:pieces20:00000000000000000000e";
+        let info_bencode = BencodeRef::decode(&info_bytes[..], BDecodeOpt::default()).unwrap();
+
+        let sorted_bytes = b"d6:lengthi0e4:name15:dummy_file_name12:piece lengthi1024e6:pieces20:00000000000000000000e";
+        assert_eq!(InfoHash::from_bytes(&sorted_bytes[..]), metainfo::compute_info_hash(&info_bencode));
+    }
+
+    #[test]
+    fn positive_info_hash_from_bytes() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY => dummy_single_file_info()
+        }).encode();
+        let metainfo = Metainfo::from_bytes(&metainfo_bytes[..]).unwrap();
+
+        assert_eq!(metainfo.info().info_hash(), metainfo::info_hash_from_bytes(&metainfo_bytes[..]).unwrap());
+    }
+
+    #[test]
+    fn negative_info_hash_from_bytes_no_info() {
+        let metainfo_bytes = (ben_map!{
+            parse::COMMENT_KEY => ben_bytes!("dummy_comment")
+        }).encode();
+
+        assert!(metainfo::info_hash_from_bytes(&metainfo_bytes[..]).is_err());
+    }
+
+    #[test]
+    fn positive_check_piece_count() {
+        let info_bytes = (ben_map!{
+            parse::NAME_KEY         => ben_bytes!("dummy_file_name"),
+            parse::LENGTH_KEY       => ben_int!(2048),
+            parse::PIECE_LENGTH_KEY => ben_int!(1024),
+            parse::PIECES_KEY       => ben_bytes!(&[0u8; sha::SHA_HASH_LEN * 2][..])
+        }).encode();
+
+        Info::from_bytes_with_opt(&info_bytes[..], ParseOpt::new().set_check_piece_count(true)).unwrap();
+    }
+
+    #[test]
+    fn negative_check_piece_count_truncated() {
+        let info_bytes = (ben_map!{
+            parse::NAME_KEY         => ben_bytes!("dummy_file_name"),
+            parse::LENGTH_KEY       => ben_int!(2049),
+            parse::PIECE_LENGTH_KEY => ben_int!(1024),
+            parse::PIECES_KEY       => ben_bytes!(&[0u8; sha::SHA_HASH_LEN * 2][..])
+        }).encode();
+
+        Info::from_bytes(&info_bytes[..]).unwrap();
+        match *Info::from_bytes_with_opt(&info_bytes[..], ParseOpt::new().set_check_piece_count(true)).unwrap_err().kind() {
+            ParseErrorKind::MissingData{ .. } => (),
+            _ => panic!("Expected A MissingData Error")
+        }
+    }
+
+    #[test]
+    fn negative_total_length_overflows() {
+        let info_bytes = multi_file_info_bytes_with_lengths(&[(&["dummy_file_zero"], i64::max_value()),
+                                                              (&["dummy_file_one"], i64::max_value()),
+                                                              (&["dummy_file_two"], i64::max_value())]);
+
+        match *Info::from_bytes(&info_bytes[..]).unwrap_err().kind() {
+            ParseErrorKind::CorruptData{ .. } => (),
+            _ => panic!("Expected A CorruptData Error")
+        }
+    }
+
+    #[test]
+    fn negative_check_piece_count_multi_file() {
+        let files = ben_list!(
+            ben_map!{ parse::LENGTH_KEY => ben_int!(1024), parse::PATH_KEY => ben_list!(ben_bytes!("dummy_file_zero")) },
+            ben_map!{ parse::LENGTH_KEY => ben_int!(1), parse::PATH_KEY => ben_list!(ben_bytes!("dummy_file_one")) }
+        );
+        let info_bytes = (ben_map!{
+            parse::NAME_KEY         => ben_bytes!("dummy_file_directory"),
+            parse::PIECE_LENGTH_KEY => ben_int!(1024),
+            parse::PIECES_KEY       => ben_bytes!(&[0u8; sha::SHA_HASH_LEN][..]),
+            parse::FILES_KEY        => files
+        }).encode();
+
+        Info::from_bytes_with_opt(&info_bytes[..], ParseOpt::new().set_check_piece_count(true)).unwrap_err();
+    }
+
+    #[test]
+    fn positive_reserialize_preserves_unknown_keys() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY    => dummy_single_file_info(),
+            parse::COMMENT_KEY => ben_bytes!("dummy_comment"),
+            "x-unknown"        => ben_list!(ben_int!(1), ben_bytes!("two"))
+        }).encode();
+
+        let metainfo = Metainfo::from_bytes_with_opt(&metainfo_bytes[..], ParseOpt::new().set_preserve_original(true)).unwrap();
+        let reserialized = metainfo.reserialize().unwrap();
+
+        assert_eq!(metainfo_bytes, reserialized);
+        assert_eq!(metainfo.info().info_hash(), metainfo::info_hash_from_bytes(&reserialized).unwrap());
+    }
+
+    #[test]
+    fn positive_reserialize_tracker_edits() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY         => dummy_single_file_info(),
+            parse::ANNOUNCE_URL_KEY => ben_bytes!("udp://dummy_tracker_zero"),
+            "x-unknown"             => ben_int!(5)
+        }).encode();
+
+        let mut metainfo = Metainfo::from_bytes_with_opt(&metainfo_bytes[..], ParseOpt::new().set_preserve_original(true)).unwrap();
+        metainfo.set_main_tracker("udp://dummy_tracker_one");
+        metainfo.add_tracker_tier(vec!["udp://dummy_tracker_two".to_owned(), "udp://dummy_tracker_three".to_owned()]);
+
+        let reserialized = Metainfo::from_bytes(metainfo.reserialize().unwrap()).unwrap();
+        let expected_tiers = vec![vec!["udp://dummy_tracker_one".to_owned()],
+                                  vec!["udp://dummy_tracker_two".to_owned(), "udp://dummy_tracker_three".to_owned()]];
+
+        assert_eq!(Some("udp://dummy_tracker_one"), reserialized.main_tracker());
+        assert_eq!(Some(&expected_tiers), reserialized.trackers());
+        assert_eq!(Some(&b"i5e"[..]), reserialized.extra_field(b"x-unknown"));
+        assert_eq!(Some(&b"23:udp://dummy_tracker_one"[..]), metainfo.extra_field(parse::ANNOUNCE_URL_KEY));
+        assert_eq!(reserialized.extra_field(parse::ANNOUNCE_LIST_KEY), metainfo.extra_field(parse::ANNOUNCE_LIST_KEY));
+        assert_eq!(metainfo.info().info_hash(), reserialized.info().info_hash());
+    }
+
+    #[test]
+    fn positive_add_tracker_tier_keeps_main_tracker() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY         => dummy_single_file_info(),
+            parse::ANNOUNCE_URL_KEY => ben_bytes!("udp://dummy_tracker_zero")
+        }).encode();
+
+        let mut metainfo = Metainfo::from_bytes(&metainfo_bytes[..]).unwrap();
+        metainfo.add_tracker_tier(vec!["udp://dummy_tracker_one".to_owned()]);
+
+        let expected_tiers = vec![vec!["udp://dummy_tracker_zero".to_owned()], vec!["udp://dummy_tracker_one".to_owned()]];
+
+        assert_eq!(Some("udp://dummy_tracker_zero"), metainfo.main_tracker());
+        assert_eq!(Some(&expected_tiers), metainfo.trackers());
+    }
+
+    #[test]
+    fn positive_parse_announce_list_without_main_tracker() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY          => dummy_single_file_info(),
+            parse::ANNOUNCE_LIST_KEY => ben_list!(ben_list!(ben_bytes!("udp://dummy_tracker")))
+        }).encode();
+        let metainfo = Metainfo::from_bytes(&metainfo_bytes[..]).unwrap();
+
+        assert_eq!(None, metainfo.main_tracker());
+        assert_eq!(Some(&vec![vec!["udp://dummy_tracker".to_owned()]]), metainfo.trackers());
+    }
+
+    #[test]
+    fn positive_trackers_owned_skips_empty_tiers() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY          => dummy_single_file_info(),
+            parse::ANNOUNCE_LIST_KEY => ben_list!(ben_list!(), ben_list!(ben_bytes!(""), ben_bytes!("udp://dummy_tracker")), ben_list!(ben_bytes!("")))
+        }).encode();
+        let metainfo = Metainfo::from_bytes(&metainfo_bytes[..]).unwrap();
+
+        assert_eq!(vec![vec!["udp://dummy_tracker".to_owned()]], metainfo.trackers_owned());
+    }
+
+    #[test]
+    fn positive_normalized_trackers_removes_duplicates() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY          => dummy_single_file_info(),
+            parse::ANNOUNCE_URL_KEY  => ben_bytes!("udp://dummy_tracker_a"),
+            parse::ANNOUNCE_LIST_KEY => ben_list!(
+                ben_list!(ben_bytes!("udp://dummy_tracker_a"), ben_bytes!("udp://dummy_tracker_b"), ben_bytes!("udp://dummy_tracker_a")),
+                ben_list!(ben_bytes!("udp://dummy_tracker_b")),
+                ben_list!(ben_bytes!("udp://dummy_tracker_c"), ben_bytes!("udp://dummy_tracker_b"))
+            )
+        }).encode();
+        let metainfo = Metainfo::from_bytes(&metainfo_bytes[..]).unwrap();
+
+        assert_eq!(vec![vec!["udp://dummy_tracker_a".to_owned(), "udp://dummy_tracker_b".to_owned()],
+                        vec!["udp://dummy_tracker_c".to_owned()]], metainfo.normalized_trackers());
+    }
+
+    #[test]
+    fn positive_normalized_trackers_main_tracker_only() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY         => dummy_single_file_info(),
+            parse::ANNOUNCE_URL_KEY => ben_bytes!("udp://dummy_tracker_a")
+        }).encode();
+        let metainfo = Metainfo::from_bytes(&metainfo_bytes[..]).unwrap();
+
+        assert_eq!(vec![vec!["udp://dummy_tracker_a".to_owned()]], metainfo.normalized_trackers());
+    }
+
+    #[test]
+    fn positive_trackers_owned_all_tiers_empty() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY          => dummy_single_file_info(),
+            parse::ANNOUNCE_LIST_KEY => ben_list!(ben_list!())
+        }).encode();
+        let metainfo = Metainfo::from_bytes(&metainfo_bytes[..]).unwrap();
+
+        assert_eq!(None, metainfo.trackers());
+        assert!(metainfo.trackers_owned().is_empty());
+    }
+
+    #[test]
+    fn negative_parse_file_empty_path_list() {
+        let info_bytes = (ben_map!{
+            parse::NAME_KEY         => ben_bytes!("dummy_file_directory"),
+            parse::PIECE_LENGTH_KEY => ben_int!(1024),
+            parse::PIECES_KEY       => ben_bytes!(&[0u8; sha::SHA_HASH_LEN][..]),
+            parse::FILES_KEY        => ben_list!(ben_map!{
+                parse::LENGTH_KEY => ben_int!(0),
+                parse::PATH_KEY   => ben_list!()
+            })
+        }).encode();
+
+        match *Info::from_bytes(&info_bytes[..]).unwrap_err().kind() {
+            ParseErrorKind::MissingData{ .. } => (),
+            _ => panic!("Expected A MissingData Error")
+        }
+    }
+
+    #[test]
+    fn negative_parse_file_empty_path_element() {
+        let info_bytes = multi_file_info_bytes(&[&["dummy_dir", ""]]);
+
+        match *Info::from_bytes(&info_bytes[..]).unwrap_err().kind() {
+            ParseErrorKind::MissingData{ .. } => (),
+            _ => panic!("Expected A MissingData Error")
+        }
+    }
+
+    #[test]
+    fn positive_comment_created_by_non_utf8() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY       => dummy_single_file_info(),
+            parse::COMMENT_KEY    => ben_bytes!(&b"dummy\xffcomment"[..]),
+            parse::CREATED_BY_KEY => ben_bytes!(&b"dummy\xfecreator"[..])
+        }).encode();
+        let metainfo = Metainfo::from_bytes(&metainfo_bytes[..]).unwrap();
+
+        assert_eq!(None, metainfo.comment());
+        assert_eq!(None, metainfo.comment_utf8());
+        assert_eq!(Some(&b"dummy\xffcomment"[..]), metainfo.comment_bytes());
+        assert_eq!(None, metainfo.created_by());
+        assert_eq!(Some(&b"dummy\xfecreator"[..]), metainfo.created_by_bytes());
+    }
+
+    #[test]
+    #[cfg(feature = "gzip")]
+    fn positive_from_gzip_bytes() {
+        use std::io::Write;
+        use flate2::Compression;
+        use flate2::write::GzEncoder;
+
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY => dummy_single_file_info()
+        }).encode();
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&metainfo_bytes).unwrap();
+        let gzip_bytes = encoder.finish().unwrap();
+
+        let metainfo = Metainfo::from_gzip_bytes(&gzip_bytes).unwrap();
+        assert_eq!(Metainfo::from_bytes(&metainfo_bytes).unwrap(), metainfo);
+    }
+
+    #[test]
+    #[cfg(feature = "gzip")]
+    fn negative_from_gzip_bytes_truncated() {
+        use std::io::Write;
+        use flate2::Compression;
+        use flate2::write::GzEncoder;
+
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY => dummy_single_file_info()
+        }).encode();
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&metainfo_bytes).unwrap();
+        let gzip_bytes = encoder.finish().unwrap();
+
+        match *Metainfo::from_gzip_bytes(&gzip_bytes[..gzip_bytes.len() / 2]).unwrap_err().kind() {
+            ParseErrorKind::CorruptData{ .. } => (),
+            _ => panic!("Expected A CorruptData Error")
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "gzip")]
+    fn negative_from_gzip_bytes_too_large() {
+        use std::io::Write;
+        use flate2::Compression;
+        use flate2::write::GzEncoder;
+
+        // Zeros compress well, so the bomb itself stays small
+        let chunk = vec![0u8; 1024 * 1024];
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::fast());
+        for _ in 0..(super::MAX_GZIP_DECOMPRESSED_LEN / chunk.len() as u64) {
+            encoder.write_all(&chunk).unwrap();
+        }
+        encoder.write_all(&[0u8]).unwrap();
+        let gzip_bytes = encoder.finish().unwrap();
+
+        match *Metainfo::from_gzip_bytes(&gzip_bytes).unwrap_err().kind() {
+            ParseErrorKind::CorruptData{ .. } => (),
+            _ => panic!("Expected A CorruptData Error")
+        }
+    }
+
+    #[test]
+    fn negative_from_file_missing_includes_path() {
+        let error = Metainfo::from_file("missing_dummy_file.torrent").unwrap_err();
+
+        assert!(error.is_io_error());
+        assert!(error.to_string().contains("Failed To Open missing_dummy_file.torrent"));
+        match *error.kind() {
+            ParseErrorKind::Io(ref io_error) => assert_eq!(io::ErrorKind::NotFound, io_error.kind()),
+            _ => panic!("Expected An Io Error")
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "mmap")]
+    fn positive_from_mmap() {
+        use std::fs;
+        use std::io::Write;
+
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY => dummy_single_file_info()
+        }).encode();
+        let path = ::std::env::temp_dir().join("bip_metainfo_positive_from_mmap.torrent");
+        fs::File::create(&path).unwrap().write_all(&metainfo_bytes).unwrap();
+
+        let metainfo = Metainfo::from_mmap(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(Metainfo::from_bytes(&metainfo_bytes).unwrap(), metainfo);
+    }
+
+    #[test]
+    #[cfg(feature = "mmap")]
+    fn negative_from_mmap_missing_includes_path() {
+        let error = Metainfo::from_mmap("missing_dummy_file.torrent").unwrap_err();
+
+        assert!(error.is_io_error());
+        assert!(error.to_string().contains("Failed To Open missing_dummy_file.torrent"));
+    }
+
+    #[test]
+    fn positive_file_verify_contents() {
+        let contents = b"dummy_file_contents";
+        let sha1: [u8; sha::SHA_HASH_LEN] = ShaHash::from_bytes(contents).into();
+
+        let mut info = dummy_single_file_info();
+        info.dict_mut().unwrap().insert(parse::LENGTH_KEY.into(), ben_int!(contents.len() as i64));
+        info.dict_mut().unwrap().insert(parse::SHA1_KEY.into(), ben_bytes!(&sha1[..]));
+        let info = Info::from_bytes(info.encode()).unwrap();
+        let file = info.files().next().unwrap();
+
+        assert_eq!(Some(&sha1), file.sha1());
+        assert_eq!(Some(true), file.verify_contents(&contents[..]).unwrap());
+        assert_eq!(Some(false), file.verify_contents(&b"dummy_file_contentz"[..]).unwrap());
+        assert_eq!(Some(false), file.verify_contents(&contents[1..]).unwrap());
+    }
+
+    #[test]
+    fn positive_file_verify_contents_no_sha1() {
+        let info = Info::from_bytes(dummy_single_file_info().encode()).unwrap();
+        let file = info.files().next().unwrap();
+
+        assert_eq!(None, file.sha1());
+        assert_eq!(None, file.verify_contents(&b""[..]).unwrap());
+    }
+
+    #[test]
+    fn positive_file_piece_ranges() {
+        let info_bytes = (ben_map!{
+            parse::NAME_KEY         => ben_bytes!("dummy_file_directory"),
+            parse::PIECE_LENGTH_KEY => ben_int!(4),
+            parse::PIECES_KEY       => ben_bytes!(&[0u8; sha::SHA_HASH_LEN * 4][..]),
+            parse::FILES_KEY        => ben_list!(
+                ben_map!{ parse::LENGTH_KEY => ben_int!(4), parse::PATH_KEY => ben_list!(ben_bytes!("aligned")) },
+                ben_map!{ parse::LENGTH_KEY => ben_int!(6), parse::PATH_KEY => ben_list!(ben_bytes!("shares_last")) },
+                ben_map!{ parse::LENGTH_KEY => ben_int!(0), parse::PATH_KEY => ben_list!(ben_bytes!("empty")) },
+                ben_map!{ parse::LENGTH_KEY => ben_int!(3), parse::PATH_KEY => ben_list!(ben_bytes!("shares_first")) }
+            )
+        }).encode();
+        let info = Info::from_bytes(&info_bytes).unwrap();
+
+        assert_eq!(vec![(0..1, false), (1..3, true), (2..2, false), (2..4, true)], info.file_piece_ranges());
+
+        let files_with_pieces: Vec<(&Path, Range<usize>)> = info.files_with_pieces()
+            .map(|(file, range)| (file.path(), range))
+            .collect();
+        assert_eq!(vec![(Path::new("aligned"), 0..1), (Path::new("shares_last"), 1..3),
+                        (Path::new("empty"), 2..2), (Path::new("shares_first"), 2..4)], files_with_pieces);
+    }
+
+    #[test]
+    fn positive_file_piece_ranges_huge_lengths() {
+        let info_bytes = (ben_map!{
+            parse::NAME_KEY         => ben_bytes!("dummy_file_directory"),
+            parse::PIECE_LENGTH_KEY => ben_int!(i64::max_value()),
+            parse::PIECES_KEY       => ben_bytes!(&[0u8; sha::SHA_HASH_LEN * 2][..]),
+            parse::FILES_KEY        => ben_list!(
+                ben_map!{ parse::LENGTH_KEY => ben_int!(i64::max_value()), parse::PATH_KEY => ben_list!(ben_bytes!("dummy_file_zero")) },
+                ben_map!{ parse::LENGTH_KEY => ben_int!(i64::max_value()), parse::PATH_KEY => ben_list!(ben_bytes!("dummy_file_one")) }
+            )
+        }).encode();
+        let info = Info::from_bytes(&info_bytes).unwrap();
+
+        assert_eq!(vec![(0..1, false), (1..2, false)], info.file_piece_ranges());
+    }
+
+    #[test]
+    fn positive_parse_info_hash_hex() {
+        let info_hash = metainfo::parse_info_hash_hex("0123456789abcdefABCDEF0123456789abcdef01").unwrap();
+        let expected = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0xab, 0xcd,
+                        0xef, 0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef, 0x01];
+
+        assert_eq!(InfoHash::from(expected), info_hash);
+    }
+
+    #[test]
+    fn negative_parse_info_hash_hex_wrong_length() {
+        match *metainfo::parse_info_hash_hex("0123").unwrap_err().kind() {
+            ParseErrorKind::CorruptData{ .. } => (),
+            _ => panic!("Expected A CorruptData Error")
+        }
+    }
+
+    #[test]
+    fn negative_parse_info_hash_hex_non_hex() {
+        match *metainfo::parse_info_hash_hex("0123456789abcdefABCDEF0123456789abcdef0g").unwrap_err().kind() {
+            ParseErrorKind::CorruptData{ .. } => (),
+            _ => panic!("Expected A CorruptData Error")
+        }
+    }
+
+    #[test]
+    fn positive_info_is_canonical() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY => dummy_single_file_info()
+        }).encode();
+
+        assert!(Metainfo::from_bytes(&metainfo_bytes).unwrap().info_is_canonical());
+    }
+
+    #[test]
+    fn positive_info_dict_size() {
+        let info_bytes = dummy_single_file_info().encode();
+        let metainfo_bytes = (ben_map!{
+            parse::COMMENT_KEY => ben_bytes!("dummy_comment"),
+            parse::INFO_KEY    => dummy_single_file_info()
+        }).encode();
+
+        let metainfo = Metainfo::from_bytes(&metainfo_bytes).unwrap();
+        assert_eq!(info_bytes.len(), metainfo.info_dict_size());
+        assert_eq!(info_bytes.len(), Info::from_bytes(&info_bytes).unwrap().info_dict_size());
+    }
+
+    #[test]
+    fn negative_info_is_canonical_nested_unsorted_keys() {
+        let info_bytes = b"d5:filesld4:pathl1:ae6:lengthi0eee4:name5:dummy12:piece lengthi1024e6:pieces20:00000000000000000000e";
+
+        let info = Info::from_bytes(&info_bytes[..]).unwrap();
+        assert!(!info.is_canonical());
+    }
+
+    #[test]
+    fn negative_info_is_canonical_unsorted_keys() {
+        let info_bytes = b"d4:name15:dummy_file_name6:lengthi0e12:piece lengthi1024e6:pieces20:00000000000000000000e";
+        let metainfo_bytes = [&b"d4:info"[..], &info_bytes[..], &b"e"[..]].concat();
+
+        let metainfo = Metainfo::from_bytes(&metainfo_bytes).unwrap();
+        assert!(!metainfo.info_is_canonical());
+        assert_eq!(InfoHash::from_bytes(&info_bytes[..]), metainfo.info().info_hash());
+    }
+
+    #[test]
+    fn positive_error_classes() {
+        let bencode_error = Metainfo::from_bytes(b"d4:info").unwrap_err();
+        assert!(bencode_error.is_corrupt_bencode());
+        assert!(!bencode_error.is_invalid_metainfo());
+
+        let metainfo_error = Metainfo::from_bytes(b"de").unwrap_err();
+        assert!(metainfo_error.is_invalid_metainfo());
+        assert!(!metainfo_error.is_io_error());
+    }
+
+    #[test]
+    fn positive_validate_bytes() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY => dummy_single_file_info()
+        }).encode();
+
+        Metainfo::validate_bytes(&metainfo_bytes).unwrap();
+    }
+
+    #[test]
+    fn positive_validate_bytes_zero_piece_length() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY => ben_map!{
+                parse::NAME_KEY         => ben_bytes!("dummy_file_name"),
+                parse::LENGTH_KEY       => ben_int!(0),
+                parse::PIECE_LENGTH_KEY => ben_int!(0),
+                parse::PIECES_KEY       => ben_bytes!(&[0u8; sha::SHA_HASH_LEN][..])
+            }
+        }).encode();
+
+        // Matches Metainfo::from_bytes, which only rejects a piece length of 0 when checking the piece count
+        Metainfo::from_bytes(&metainfo_bytes).unwrap();
+        Metainfo::validate_bytes(&metainfo_bytes).unwrap();
+    }
+
+    #[test]
+    fn negative_validate_bytes_duplicate_files() {
+        let info_bytes = multi_file_info_bytes(&[&["dummy_file_name"], &["dummy_file_name"]]);
+        let mut metainfo_bytes = b"d4:info".to_vec();
+        metainfo_bytes.extend_from_slice(&info_bytes);
+        metainfo_bytes.push(b'e');
+
+        assert!(Metainfo::from_bytes(&metainfo_bytes).is_err());
+        match *Metainfo::validate_bytes(&metainfo_bytes).unwrap_err().kind() {
+            ParseErrorKind::CorruptData{ .. } => (),
+            _ => panic!("Expected A CorruptData Error")
+        }
+    }
+
+    #[test]
+    fn positive_validate_bytes_same_file_name_in_sub_directory() {
+        let info_bytes = multi_file_info_bytes(&[&["dummy_sub_directory", "dummy_file_name"], &["dummy_file_name"]]);
+        let mut metainfo_bytes = b"d4:info".to_vec();
+        metainfo_bytes.extend_from_slice(&info_bytes);
+        metainfo_bytes.push(b'e');
+
+        Metainfo::from_bytes(&metainfo_bytes).unwrap();
+        Metainfo::validate_bytes(&metainfo_bytes).unwrap();
+    }
+
+    #[test]
+    fn negative_validate_bytes_total_length_overflows() {
+        let info_bytes = multi_file_info_bytes_with_lengths(&[(&["dummy_file_zero"], i64::max_value()),
+                                                              (&["dummy_file_one"], i64::max_value()),
+                                                              (&["dummy_file_two"], i64::max_value())]);
+        let mut metainfo_bytes = b"d4:info".to_vec();
+        metainfo_bytes.extend_from_slice(&info_bytes);
+        metainfo_bytes.push(b'e');
+
+        assert!(Metainfo::from_bytes(&metainfo_bytes).is_err());
+        match *Metainfo::validate_bytes(&metainfo_bytes).unwrap_err().kind() {
+            ParseErrorKind::CorruptData{ .. } => (),
+            _ => panic!("Expected A CorruptData Error")
+        }
+    }
+
+    #[test]
+    fn negative_validate_bytes_empty_path_element() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY => ben_map!{
+                parse::NAME_KEY         => ben_bytes!("dummy_file_directory"),
+                parse::PIECE_LENGTH_KEY => ben_int!(1024),
+                parse::PIECES_KEY       => ben_bytes!(&[0u8; sha::SHA_HASH_LEN][..]),
+                parse::FILES_KEY        => ben_list!(ben_map!{
+                    parse::LENGTH_KEY => ben_int!(0),
+                    parse::PATH_KEY   => ben_list!(ben_bytes!(""))
+                })
+            }
+        }).encode();
+
+        match *Metainfo::validate_bytes(&metainfo_bytes).unwrap_err().kind() {
+            ParseErrorKind::MissingData{ .. } => (),
+            _ => panic!("Expected A MissingData Error")
+        }
+    }
+
+    #[test]
+    fn positive_summary() {
+        let info = Info::from_bytes(multi_file_info_bytes(&[&["dummy_file_zero"], &["dummy_file_one"]])).unwrap();
+        let metainfo = Metainfo::from(info.clone());
+        let summary = metainfo.summary();
+
+        assert_eq!(info.name_utf8(), summary.name());
+        assert_eq!(info.total_length(), summary.total_length());
+        assert_eq!(2, summary.num_files());
+        assert_eq!(info.pieces().len(), summary.num_pieces());
+        assert_eq!(info.info_hash(), summary.info_hash());
+    }
+
+    #[test]
+    fn positive_is_hybrid() {
+        let info_bytes = (ben_map!{
+            parse::NAME_KEY         => ben_bytes!("dummy_file_name"),
+            parse::LENGTH_KEY       => ben_int!(0),
+            parse::PIECE_LENGTH_KEY => ben_int!(1024),
+            parse::PIECES_KEY       => ben_bytes!(&[0u8; sha::SHA_HASH_LEN][..]),
+            parse::META_VERSION_KEY => ben_int!(2),
+            parse::FILE_TREE_KEY    => ben_map!{}
+        }).encode();
+        let info = Info::from_bytes(&info_bytes).unwrap();
+
+        assert!(info.is_hybrid());
+        assert_eq!(InfoHash::from_bytes(&info_bytes), info.info_hash_v1());
+        assert_eq!(Some(InfoHash::from_bytes_truncated_sha256(&info_bytes)), info.info_hash_v2());
+    }
+
+    #[test]
+    fn positive_is_hybrid_missing_file_tree() {
+        let info_bytes = (ben_map!{
+            parse::NAME_KEY         => ben_bytes!("dummy_file_name"),
+            parse::LENGTH_KEY       => ben_int!(0),
+            parse::PIECE_LENGTH_KEY => ben_int!(1024),
+            parse::PIECES_KEY       => ben_bytes!(&[0u8; sha::SHA_HASH_LEN][..]),
+            parse::META_VERSION_KEY => ben_int!(2)
+        }).encode();
+        let info = Info::from_bytes(&info_bytes).unwrap();
+
+        assert!(!info.is_hybrid());
+        assert_eq!(None, info.info_hash_v2());
+    }
+
+    #[test]
+    fn positive_hash_kind_sha1() {
+        let info = Info::from_bytes(dummy_single_file_info().encode()).unwrap();
+
+        assert_eq!(HashKind::Sha1, info.hash_kind());
+        assert!(info.pieces().all(|hash| hash.len() == sha::SHA_HASH_LEN));
+    }
+
+    #[test]
+    fn positive_piece_layers() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY         => dummy_single_file_info(),
+            parse::PIECE_LAYERS_KEY => ben_map!{
+                &[1u8; SHA256_HASH_LEN][..] => ben_bytes!(&[2u8; SHA256_HASH_LEN * 2][..])
+            }
+        }).encode();
+        let metainfo = Metainfo::from_bytes(&metainfo_bytes[..]).unwrap();
+
+        assert_eq!(1, metainfo.piece_layers().len());
+        assert_eq!(Some(&vec![2u8; SHA256_HASH_LEN * 2]), metainfo.piece_layers().get(&[1u8; SHA256_HASH_LEN][..]));
+        assert!(metainfo.extra_field(parse::PIECE_LAYERS_KEY).is_none());
+    }
+
+    #[test]
+    fn negative_piece_layers_partial_hash() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY         => dummy_single_file_info(),
+            parse::PIECE_LAYERS_KEY => ben_map!{
+                &[1u8; SHA256_HASH_LEN][..] => ben_bytes!(&[2u8; SHA256_HASH_LEN + 1][..])
+            }
+        }).encode();
+
+        match *Metainfo::from_bytes(&metainfo_bytes[..]).unwrap_err().kind() {
+            ParseErrorKind::MissingData{ .. } => (),
+            _ => panic!("Expected A MissingData Error")
+        }
+    }
+
+    #[test]
+    fn negative_reserialize_not_preserved() {
+        let metainfo_bytes = (ben_map!{
+            parse::INFO_KEY => dummy_single_file_info()
+        }).encode();
+        let metainfo = Metainfo::from_bytes(&metainfo_bytes[..]).unwrap();
+
+        assert_eq!(None, metainfo.reserialize());
+    }
+
+    /// Helper function for building an info dictionary for the given file contents with a piece length of 4.
+    fn verify_info(file_contents: &[(&str, &[u8])]) -> Info {
+        verify_info_with_piece_length(4, file_contents)
+    }
+
+    fn verify_info_with_piece_length(piece_len: usize, file_contents: &[(&str, &[u8])]) -> Info {
+        let all_contents: Vec<u8> = file_contents.iter().flat_map(|&(_, content)| content.iter().cloned()).collect();
+        let pieces: Vec<u8> = all_contents.chunks(piece_len)
+            .flat_map(|chunk| {
+                let hash: [u8; sha::SHA_HASH_LEN] = ShaHash::from_bytes(chunk).into();
+                hash.to_vec()
+            })
+            .collect();
+
+        let files: BencodeMut = file_contents.iter()
+            .map(|&(name, content)| ben_map!{
+                parse::LENGTH_KEY => ben_int!(content.len() as i64),
+                parse::PATH_KEY   => ben_list!(ben_bytes!(name))
+            })
+            .collect();
+
+        Info::from_bytes((ben_map!{
+            parse::NAME_KEY         => ben_bytes!("dummy_file_directory"),
+            parse::PIECE_LENGTH_KEY => ben_int!(piece_len as i64),
+            parse::PIECES_KEY       => ben_bytes!(&pieces[..]),
+            parse::FILES_KEY        => files
+        }).encode()).unwrap()
+    }
+
+    #[test]
+    fn positive_verify_files_all_good() {
+        let file_contents: &[(&str, &[u8])] = &[("dummy_file_zero", b"abcdef"), ("dummy_file_one", b"ghijk")];
+        let info = verify_info(file_contents);
+
+        let report = info.verify_files(|path| {
+            let name = path.file_name().unwrap().to_str().unwrap();
+            let &(_, content) = file_contents.iter().find(|&&(n, _)| n == name).unwrap();
+
+            Ok(Cursor::new(content))
+        });
+
+        assert_eq!(3, report.num_pieces());
+        assert_eq!(3, report.num_good());
+        assert_eq!(&[0b11100000][..], report.bitfield());
+    }
+
+    #[test]
+    fn negative_verify_files_missing_and_corrupt() {
+        let file_contents: &[(&str, &[u8])] = &[("dummy_file_zero", b"abcdef"), ("dummy_file_one", b"ghijk")];
+        let info = verify_info(file_contents);
+
+        // First file is missing, second file is corrupt in the last piece
+        let report = info.verify_files(|path| {
+            if path.ends_with("dummy_file_zero") {
+                Err(io::Error::new(io::ErrorKind::NotFound, "dummy_file_zero"))
+            } else {
+                Ok(Cursor::new(&b"ghijz"[..]))
+            }
+        });
+
+        assert_eq!(3, report.num_bad());
+        assert_eq!(Some(false), report.is_good(0));
+        assert_eq!(Some(false), report.is_good(2));
+        assert_eq!(None, report.is_good(3));
+    }
+
+    #[test]
+    fn positive_verify_files_padding_not_opened() {
+        let all_contents = b"abcdef\0\0ghij";
+        let pieces: Vec<u8> = all_contents.chunks(4)
+            .flat_map(|chunk| {
+                let hash: [u8; sha::SHA_HASH_LEN] = ShaHash::from_bytes(chunk).into();
+                hash.to_vec()
+            })
+            .collect();
+        let info = Info::from_bytes((ben_map!{
+            parse::NAME_KEY         => ben_bytes!("dummy_file_directory"),
+            parse::PIECE_LENGTH_KEY => ben_int!(4),
+            parse::PIECES_KEY       => ben_bytes!(&pieces[..]),
+            parse::FILES_KEY        => ben_list!(
+                ben_map!{
+                    parse::LENGTH_KEY => ben_int!(6),
+                    parse::PATH_KEY   => ben_list!(ben_bytes!("dummy_file_zero"))
+                },
+                ben_map!{
+                    parse::LENGTH_KEY => ben_int!(2),
+                    parse::PATH_KEY   => ben_list!(ben_bytes!(".pad"), ben_bytes!("2")),
+                    parse::ATTR_KEY   => ben_bytes!("p")
+                },
+                ben_map!{
+                    parse::LENGTH_KEY => ben_int!(4),
+                    parse::PATH_KEY   => ben_list!(ben_bytes!("dummy_file_one"))
+                }
+            )
+        }).encode()).unwrap();
+
+        let report = info.verify_files(|path| {
+            if path.ends_with("dummy_file_zero") {
+                Ok(Cursor::new(&b"abcdef"[..]))
+            } else if path.ends_with("dummy_file_one") {
+                Ok(Cursor::new(&b"ghij"[..]))
+            } else {
+                panic!("Padding File Should Not Be Opened")
+            }
+        });
+
+        assert_eq!(3, report.num_pieces());
+        assert_eq!(3, report.num_good());
+    }
+
+    #[test]
+    fn positive_verify_files_piece_spans_chunks() {
+        let content: Vec<u8> = (0..100 * 1024).map(|index| index as u8).collect();
+        let file_contents: &[(&str, &[u8])] = &[("dummy_file_zero", &content[..70 * 1024]),
+                                                ("dummy_file_one", &content[70 * 1024..])];
+        let info = verify_info_with_piece_length(64 * 1024, file_contents);
+
+        let report = info.verify_files(|path| {
+            let name = path.file_name().unwrap().to_str().unwrap();
+            let &(_, content) = file_contents.iter().find(|&&(file_name, _)| file_name == name).unwrap();
+
+            Ok(Cursor::new(content))
+        });
+
+        assert_eq!(2, report.num_pieces());
+        assert_eq!(2, report.num_good());
+    }
+
+    #[test]
+    fn positive_verify_files_short_file() {
+        let file_contents: &[(&str, &[u8])] = &[("dummy_file_zero", b"abcdef"), ("dummy_file_one", b"ghijk")];
+        let info = verify_info(file_contents);
+
+        // Second file is truncated, only the first piece is intact
+        let report = info.verify_files(|path| {
+            if path.ends_with("dummy_file_zero") {
+                Ok(Cursor::new(&b"abcdef"[..]))
+            } else {
+                Ok(Cursor::new(&b"g"[..]))
+            }
+        });
+
+        assert_eq!(1, report.num_good());
+        assert_eq!(Some(true), report.is_good(0));
+    }
+
+    #[test]
+    fn positive_file_attributes() {
+        let files = ben_list!(
+            ben_map!{
+                parse::LENGTH_KEY => ben_int!(0),
+                parse::PATH_KEY   => ben_list!(ben_bytes!("dummy_file_zero")),
+                parse::ATTR_KEY   => ben_bytes!("x"),
+                parse::SHA1_KEY   => ben_bytes!(&[1u8; sha::SHA_HASH_LEN][..])
+            },
+            ben_map!{
+                parse::LENGTH_KEY => ben_int!(0),
+                parse::PATH_KEY   => ben_list!(ben_bytes!(".pad"), ben_bytes!("0")),
+                parse::ATTR_KEY   => ben_bytes!("p")
+            },
+            ben_map!{
+                parse::LENGTH_KEY       => ben_int!(0),
+                parse::PATH_KEY         => ben_list!(ben_bytes!("dummy_link")),
+                parse::ATTR_KEY         => ben_bytes!("l"),
+                parse::SYMLINK_PATH_KEY => ben_list!(ben_bytes!("dummy_dir"), ben_bytes!("dummy_file_zero"))
+            }
+        );
+        let info = Info::from_bytes((ben_map!{
+            parse::NAME_KEY         => ben_bytes!("dummy_file_directory"),
+            parse::PIECE_LENGTH_KEY => ben_int!(1024),
+            parse::PIECES_KEY       => ben_bytes!(&[0u8; sha::SHA_HASH_LEN][..]),
+            parse::FILES_KEY        => files
+        }).encode()).unwrap();
+        let files: Vec<&File> = info.files().collect();
+
+        assert_eq!(Some("x"), files[0].attributes());
+        assert_eq!(Some(&[1u8; sha::SHA_HASH_LEN]), files[0].sha1());
+        assert!(!files[0].is_padding());
+
+        assert!(files[1].is_padding());
+
+        assert_eq!(Some(Path::new("dummy_dir/dummy_file_zero")), files[2].symlink_target());
+        assert_eq!(None, files[0].symlink_target());
+    }
+
+    #[test]
+    fn negative_symlink_target_unsafe() {
+        let unsafe_targets = vec![ben_list!(ben_bytes!(".."), ben_bytes!("etc"), ben_bytes!("passwd")),
+                                  ben_list!(ben_bytes!("/etc")),
+                                  ben_list!(ben_bytes!("dummy_dir"), ben_bytes!(""))];
+
+        for target in unsafe_targets {
+            let mut info_bencode = dummy_single_file_info();
+            info_bencode.dict_mut().unwrap().insert(parse::SYMLINK_PATH_KEY.into(), target);
+
+            let info = Info::from_bytes(info_bencode.encode()).unwrap();
+            assert_eq!(None, info.files().next().unwrap().symlink_target());
+        }
+    }
+
+    #[test]
+    fn positive_content_files_skips_padding() {
+        let file = |name: &'static str, attr: &'static str| ben_map!{
+            parse::LENGTH_KEY => ben_int!(0),
+            parse::PATH_KEY   => ben_list!(ben_bytes!(name)),
+            parse::ATTR_KEY   => ben_bytes!(attr)
+        };
+        let files = ben_list!(file("dummy_file_zero", ""), file("pad_zero", "p"), file("dummy_file_one", "x"),
+                              file("pad_one", "hp"));
+        let info = Info::from_bytes((ben_map!{
+            parse::NAME_KEY         => ben_bytes!("dummy_file_directory"),
+            parse::PIECE_LENGTH_KEY => ben_int!(1024),
+            parse::PIECES_KEY       => ben_bytes!(&[0u8; sha::SHA_HASH_LEN][..]),
+            parse::FILES_KEY        => files
+        }).encode()).unwrap();
+
+        let content_paths: Vec<&Path> = info.content_files().map(|file| file.path()).collect();
+        assert_eq!(vec![Path::new("dummy_file_zero"), Path::new("dummy_file_one")], content_paths);
+        assert_eq!(4, info.files().count());
+    }
 }
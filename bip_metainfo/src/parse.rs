@@ -25,22 +25,36 @@ pub const ANNOUNCE_LIST_KEY: &'static [u8] = b"announce-list";
 pub const ANNOUNCE_URL_KEY:  &'static [u8] = b"announce";
 pub const CREATION_DATE_KEY: &'static [u8] = b"creation date";
 pub const COMMENT_KEY:       &'static [u8] = b"comment";
+pub const COMMENT_UTF8_KEY:  &'static [u8] = b"comment.utf-8";
 pub const CREATED_BY_KEY:    &'static [u8] = b"created by";
 pub const ENCODING_KEY:      &'static [u8] = b"encoding";
+pub const HTTP_SEEDS_KEY:    &'static [u8] = b"httpseeds";
 pub const INFO_KEY:          &'static [u8] = b"info";
+pub const PIECE_LAYERS_KEY:  &'static [u8] = b"piece layers";
 
 /// Keys found within the info dictionary of a metainfo file.
 pub const PIECE_LENGTH_KEY: &'static [u8] = b"piece length";
 pub const PIECES_KEY:       &'static [u8] = b"pieces";
 pub const PRIVATE_KEY:      &'static [u8] = b"private";
 pub const NAME_KEY:         &'static [u8] = b"name";
+pub const NAME_UTF8_KEY:    &'static [u8] = b"name.utf-8";
 pub const FILES_KEY:        &'static [u8] = b"files";
+pub const SOURCE_KEY:       &'static [u8] = b"source";
+pub const META_VERSION_KEY: &'static [u8] = b"meta version";
+pub const FILE_TREE_KEY:    &'static [u8] = b"file tree";
+pub const SIMILAR_KEY:      &'static [u8] = b"similar";
+pub const COLLECTIONS_KEY:  &'static [u8] = b"collections";
 
 /// Keys found within the files dictionary of a metainfo file.
 pub const LENGTH_KEY: &'static [u8] = b"length";
 pub const MD5SUM_KEY: &'static [u8] = b"md5sum";
 pub const PATH_KEY:   &'static [u8] = b"path";
 
+/// Keys found within the info or files dictionary for BEP-47 file attributes.
+pub const ATTR_KEY:         &'static [u8] = b"attr";
+pub const SHA1_KEY:         &'static [u8] = b"sha1";
+pub const SYMLINK_PATH_KEY: &'static [u8] = b"symlink path";
+
 /// Parses the root bencode as a dictionary.
 pub fn parse_root_dict<B>(root_bencode: &B) -> ParseResult<&BDictAccess<B::BKey, B::BType>>
     where B: BRefAccess {
@@ -53,7 +67,7 @@ pub fn parse_announce_list<B>(root_dict: &BDictAccess<B::BKey, B>) -> Option<&BL
     CONVERT.lookup_and_convert_list(root_dict, ANNOUNCE_LIST_KEY).ok()
 }
 
-/// Converts list of lists to vec of vecs
+/// Converts list of lists to vec of vecs, skipping empty urls and tiers.
 pub fn convert_announce_list<B>(list: &BListAccess<B>) -> Vec<Vec<String>>
     where B: BRefAccess<BType=B> {
     list.into_iter()
@@ -61,9 +75,27 @@ pub fn convert_announce_list<B>(list: &BListAccess<B>) -> Vec<Vec<String>>
         .map(|entry| {
             entry.into_iter()
                 .filter_map(|bencode_str| bencode_str.str())
+                .filter(|url| !url.is_empty())
                 .map(String::from)
-                .collect()
+                .collect::<Vec<String>>()
         })
+        .filter(|tier| !tier.is_empty())
+        .collect()
+}
+
+/// Parses the http seeds list from the root dictionary.
+pub fn parse_http_seeds<B>(root_dict: &BDictAccess<B::BKey, B>) -> Option<&BListAccess<B>>
+    where B: BRefAccess<BType=B> {
+    CONVERT.lookup_and_convert_list(root_dict, HTTP_SEEDS_KEY).ok()
+}
+
+/// Converts list of http seeds to a vec, skipping entries that are not http urls.
+pub fn convert_http_seeds<B>(list: &BListAccess<B>) -> Vec<String>
+    where B: BRefAccess<BType=B> {
+    list.into_iter()
+        .filter_map(|bencode_str| bencode_str.str())
+        .filter(|url| url.starts_with("http://") || url.starts_with("https://"))
+        .map(String::from)
         .collect()
 }
 
@@ -74,9 +106,23 @@ pub fn parse_announce_url<'a, B>(root_dict: &'a BDictAccess<B::BKey, B>) -> Opti
 }
 
 /// Parses the creation date from the root dictionary.
-pub fn parse_creation_date<B>(root_dict: &BDictAccess<B::BKey, B>) -> Option<i64>
+///
+/// If lenient, a byte string holding an integer or float epoch is also accepted, floats are truncated.
+pub fn parse_creation_date<B>(root_dict: &BDictAccess<B::BKey, B>, lenient: bool) -> Option<i64>
     where B: BRefAccess {
-    CONVERT.lookup_and_convert_int(root_dict, CREATION_DATE_KEY).ok()
+    let opt_creation_date = CONVERT.lookup_and_convert_int(root_dict, CREATION_DATE_KEY).ok();
+
+    if opt_creation_date.is_none() && lenient {
+        CONVERT.lookup_and_convert_str(root_dict, CREATION_DATE_KEY).ok()
+            .and_then(|date| {
+                let date = date.trim();
+
+                date.parse::<i64>().ok()
+                    .or_else(|| date.parse::<f64>().ok().and_then(|d| if d.is_finite() { Some(d as i64) } else { None }))
+            })
+    } else {
+        opt_creation_date
+    }
 }
 
 /// Parses the comment from the root dictionary.
@@ -85,12 +131,30 @@ pub fn parse_comment<'a, B>(root_dict: &'a BDictAccess<B::BKey, B>) -> Option<&'
     CONVERT.lookup_and_convert_str(root_dict, COMMENT_KEY).ok()
 }
 
+/// Parses the comment bytes from the root dictionary.
+pub fn parse_comment_bytes<'a, B>(root_dict: &'a BDictAccess<B::BKey, B>) -> Option<&'a [u8]>
+    where B: BRefAccess + 'a {
+    CONVERT.lookup_and_convert_bytes(root_dict, COMMENT_KEY).ok()
+}
+
+/// Parses the utf-8 comment from the root dictionary.
+pub fn parse_comment_utf8<'a, B>(root_dict: &'a BDictAccess<B::BKey, B>) -> Option<&'a str>
+    where B: BRefAccess + 'a {
+    CONVERT.lookup_and_convert_str(root_dict, COMMENT_UTF8_KEY).ok()
+}
+
 /// Parses the created by from the root dictionary.
 pub fn parse_created_by<'a, B>(root_dict: &'a BDictAccess<B::BKey, B>) -> Option<&'a str>
     where B: BRefAccess + 'a {
     CONVERT.lookup_and_convert_str(root_dict, CREATED_BY_KEY).ok()
 }
 
+/// Parses the created by bytes from the root dictionary.
+pub fn parse_created_by_bytes<'a, B>(root_dict: &'a BDictAccess<B::BKey, B>) -> Option<&'a [u8]>
+    where B: BRefAccess + 'a {
+    CONVERT.lookup_and_convert_bytes(root_dict, CREATED_BY_KEY).ok()
+}
+
 /// Parses the encoding from the root dictionary.
 pub fn parse_encoding<'a, B>(root_dict: &'a BDictAccess<B::BKey, B>) -> Option<&'a str>
     where B: BRefAccess + 'a {
@@ -103,6 +167,18 @@ pub fn parse_info_bencode<'a, B>(root_dict: &'a BDictAccess<B::BKey, B>) -> Pars
     CONVERT.lookup(root_dict, INFO_KEY)
 }
 
+/// Parses the piece layers dictionary from the root dictionary.
+pub fn parse_piece_layers<B>(root_dict: &BDictAccess<B::BKey, B>) -> Option<&BDictAccess<B::BKey, B>>
+    where B: BRefAccess<BType=B> {
+    CONVERT.lookup_and_convert_dict(root_dict, PIECE_LAYERS_KEY).ok()
+}
+
+/// Parses the piece layer bytes from the piece layer bencode.
+pub fn parse_piece_layer_bytes<B>(layer_bencode: &B) -> ParseResult<&[u8]>
+    where B: BRefAccess {
+    CONVERT.convert_bytes(layer_bencode, PIECE_LAYERS_KEY)
+}
+
 // ----------------------------------------------------------------------------//
 
 /// Parses the piece length from the info dictionary.
@@ -118,9 +194,17 @@ pub fn parse_pieces<'a, B>(info_dict: &'a BDictAccess<B::BKey, B>) -> ParseResul
 }
 
 /// Parses the private flag from the info dictionary.
-pub fn parse_private<B>(info_dict: &BDictAccess<B::BKey, B>) -> Option<bool>
+///
+/// BEP-27 requires the integer form, if lenient then the byte string form is also accepted.
+pub fn parse_private<B>(info_dict: &BDictAccess<B::BKey, B>, lenient: bool) -> Option<bool>
     where B: BRefAccess {
-    CONVERT.lookup_and_convert_int(info_dict, PRIVATE_KEY).ok().map(|p| p == 1)
+    let opt_private = CONVERT.lookup_and_convert_int(info_dict, PRIVATE_KEY).ok().map(|p| p == 1);
+
+    if opt_private.is_none() && lenient {
+        CONVERT.lookup_and_convert_bytes(info_dict, PRIVATE_KEY).ok().map(|p| p == b"1")
+    } else {
+        opt_private
+    }
 }
 
 /// Parses the name from the info dictionary.
@@ -129,6 +213,60 @@ pub fn parse_name<'a, B>(info_dict: &'a BDictAccess<B::BKey, B>) -> ParseResult<
     CONVERT.lookup_and_convert_str(info_dict, NAME_KEY)
 }
 
+/// Parses the name bytes from the info dictionary.
+pub fn parse_name_bytes<'a, B>(info_dict: &'a BDictAccess<B::BKey, B>) -> ParseResult<&'a [u8]>
+    where B: BRefAccess + 'a {
+    CONVERT.lookup_and_convert_bytes(info_dict, NAME_KEY)
+}
+
+/// Parses the utf-8 name from the info dictionary.
+pub fn parse_name_utf8<'a, B>(info_dict: &'a BDictAccess<B::BKey, B>) -> Option<&'a str>
+    where B: BRefAccess + 'a {
+    CONVERT.lookup_and_convert_str(info_dict, NAME_UTF8_KEY).ok()
+}
+
+/// Parses the source bytes from the info dictionary.
+pub fn parse_source_bytes<'a, B>(info_dict: &'a BDictAccess<B::BKey, B>) -> Option<&'a [u8]>
+    where B: BRefAccess + 'a {
+    CONVERT.lookup_and_convert_bytes(info_dict, SOURCE_KEY).ok()
+}
+
+/// Parses the meta version from the info dictionary.
+pub fn parse_meta_version<B>(info_dict: &BDictAccess<B::BKey, B>) -> Option<i64>
+    where B: BRefAccess {
+    CONVERT.lookup_and_convert_int(info_dict, META_VERSION_KEY).ok()
+}
+
+/// Parses the file tree from the info dictionary.
+pub fn parse_file_tree<B>(info_dict: &BDictAccess<B::BKey, B>) -> Option<&BDictAccess<B::BKey, B::BType>>
+    where B: BRefAccess {
+    CONVERT.lookup_and_convert_dict(info_dict, FILE_TREE_KEY).ok()
+}
+
+/// Parses the similar torrents list from the info dictionary.
+pub fn parse_similar<B>(info_dict: &BDictAccess<B::BKey, B>) -> Option<&BListAccess<B>>
+    where B: BRefAccess<BType=B> {
+    CONVERT.lookup_and_convert_list(info_dict, SIMILAR_KEY).ok()
+}
+
+/// Parses the info hash bytes from the similar bencode.
+pub fn parse_similar_bytes<B>(similar_bencode: &B) -> ParseResult<&[u8]>
+    where B: BRefAccess {
+    CONVERT.convert_bytes(similar_bencode, SIMILAR_KEY)
+}
+
+/// Parses the collections list from the info dictionary.
+pub fn parse_collections<B>(info_dict: &BDictAccess<B::BKey, B>) -> Option<&BListAccess<B>>
+    where B: BRefAccess<BType=B> {
+    CONVERT.lookup_and_convert_list(info_dict, COLLECTIONS_KEY).ok()
+}
+
+/// Parses the collection string from the collection bencode.
+pub fn parse_collection_str<B>(collection_bencode: &B) -> ParseResult<&str>
+    where B: BRefAccess {
+    CONVERT.convert_str(collection_bencode, COLLECTIONS_KEY)
+}
+
 /// Parses the files list from the info dictionary.
 pub fn parse_files_list<B>(info_dict: &BDictAccess<B::BKey, B>) -> ParseResult<&BListAccess<B>>
     where B: BRefAccess<BType=B> {
@@ -155,12 +293,36 @@ pub fn parse_md5sum<'a, B>(info_or_file_dict: &'a BDictAccess<B::BKey, B>) -> Op
     CONVERT.lookup_and_convert_bytes(info_or_file_dict, MD5SUM_KEY).ok()
 }
 
+/// Parses the attributes from the info or file dictionary.
+pub fn parse_attr<'a, B>(info_or_file_dict: &'a BDictAccess<B::BKey, B>) -> Option<&'a str>
+    where B: BRefAccess + 'a {
+    CONVERT.lookup_and_convert_str(info_or_file_dict, ATTR_KEY).ok()
+}
+
+/// Parses the sha1 from the info or file dictionary.
+pub fn parse_sha1<'a, B>(info_or_file_dict: &'a BDictAccess<B::BKey, B>) -> Option<&'a [u8]>
+    where B: BRefAccess + 'a {
+    CONVERT.lookup_and_convert_bytes(info_or_file_dict, SHA1_KEY).ok()
+}
+
+/// Parses the symlink path list from the info or file dictionary.
+pub fn parse_symlink_path_list<B>(info_or_file_dict: &BDictAccess<B::BKey, B>) -> Option<&BListAccess<B>>
+    where B: BRefAccess<BType=B> {
+    CONVERT.lookup_and_convert_list(info_or_file_dict, SYMLINK_PATH_KEY).ok()
+}
+
 /// Parses the path list from the file dictionary.
 pub fn parse_path_list<B>(file_dict: &BDictAccess<B::BKey, B>) -> ParseResult<&BListAccess<B>>
     where B: BRefAccess<BType=B> {
     CONVERT.lookup_and_convert_list(file_dict, PATH_KEY)
 }
 
+/// Parses the path bytes from the path bencode.
+pub fn parse_path_bytes<B>(path_bencode: &B) -> ParseResult<&[u8]>
+    where B: BRefAccess {
+    CONVERT.convert_bytes(path_bencode, PATH_KEY)
+}
+
 /// Parses the path string from the path bencode.
 pub fn parse_path_str<B>(path_bencode: &B) -> ParseResult<&str>
     where B: BRefAccess {
@@ -0,0 +1,88 @@
+//! Options for modifying metainfo parsing behavior.
+use std::default::Default;
+
+const DEFAULT_CHECK_DUPLICATE_FILES: bool = true;
+const DEFAULT_LENIENT:               bool = false;
+const DEFAULT_PRESERVE_ORIGINAL:     bool = false;
+const DEFAULT_CHECK_PIECE_COUNT:     bool = false;
+
+/// Stores parsing options for modifying metainfo parse behavior.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ParseOpt {
+    check_duplicate_files: bool,
+    lenient:               bool,
+    preserve_original:     bool,
+    check_piece_count:     bool
+}
+
+impl ParseOpt {
+    /// Create a new `ParseOpt` object with default values set.
+    pub fn new() -> ParseOpt {
+        ParseOpt{ check_duplicate_files: DEFAULT_CHECK_DUPLICATE_FILES, lenient: DEFAULT_LENIENT,
+                  preserve_original: DEFAULT_PRESERVE_ORIGINAL, check_piece_count: DEFAULT_CHECK_PIECE_COUNT }
+    }
+
+    /// Set whether or not an error should be thrown for multi file torrents
+    /// containing two files with the same path.
+    pub fn set_check_duplicate_files(mut self, check_duplicate_files: bool) -> ParseOpt {
+        self.check_duplicate_files = check_duplicate_files;
+
+        self
+    }
+
+    /// Set whether or not non-standard encodings written by legacy tools should be accepted.
+    ///
+    /// For example, a `private` flag encoded as the byte string `"1"` instead of an integer, or
+    /// a name or file path that is not valid UTF-8, which is converted lossily (see `File::decoded_paths`).
+    pub fn set_lenient(mut self, lenient: bool) -> ParseOpt {
+        self.lenient = lenient;
+
+        self
+    }
+
+    /// Set whether or not the original metainfo bytes should be retained after parsing.
+    ///
+    /// Required for `Metainfo::reserialize`, which reproduces the original file, including
+    /// any keys not modeled by `Metainfo`, instead of rebuilding it from the parsed fields.
+    pub fn set_preserve_original(mut self, preserve_original: bool) -> ParseOpt {
+        self.preserve_original = preserve_original;
+
+        self
+    }
+
+    /// Set whether or not an error should be thrown for torrents where the number
+    /// of piece hashes does not match the total length of the files.
+    pub fn set_check_piece_count(mut self, check_piece_count: bool) -> ParseOpt {
+        self.check_piece_count = check_piece_count;
+
+        self
+    }
+
+    /// Whether or not an error should be thrown for multi file torrents
+    /// containing two files with the same path.
+    pub fn check_duplicate_files(&self) -> bool {
+        self.check_duplicate_files
+    }
+
+    /// Whether or not non-standard encodings written by legacy tools should be accepted.
+    pub fn lenient(&self) -> bool {
+        self.lenient
+    }
+
+    /// Whether or not the original metainfo bytes should be retained after parsing.
+    pub fn preserve_original(&self) -> bool {
+        self.preserve_original
+    }
+
+    /// Whether or not an error should be thrown for torrents where the number
+    /// of piece hashes does not match the total length of the files.
+    pub fn check_piece_count(&self) -> bool {
+        self.check_piece_count
+    }
+}
+
+impl Default for ParseOpt {
+    fn default() -> ParseOpt {
+        ParseOpt::new()
+    }
+}
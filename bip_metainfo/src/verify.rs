@@ -0,0 +1,132 @@
+//! Verifying on disk data against the pieces of a torrent.
+use std::cmp;
+use std::io::{self, Read};
+use std::path::Path;
+
+use bip_util::sha::ShaHashBuilder;
+
+use metainfo::Info;
+
+/// Number of bytes read from a file at a time, regardless of the piece length.
+const READ_CHUNK_LEN: usize = 16 * 1024;
+
+/// Results of verifying each piece of a torrent.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VerifyReport {
+    bitfield:   Vec<u8>,
+    num_pieces: usize,
+    num_good:   usize,
+}
+
+impl VerifyReport {
+    fn new(num_pieces: usize) -> VerifyReport {
+        VerifyReport{ bitfield: vec![0u8; (num_pieces + 7) / 8], num_pieces: num_pieces, num_good: 0 }
+    }
+
+    fn set_good(&mut self, index: usize) {
+        self.bitfield[index / 8] |= 0x80 >> (index % 8);
+        self.num_good += 1;
+    }
+
+    /// Total number of pieces in the torrent.
+    pub fn num_pieces(&self) -> usize {
+        self.num_pieces
+    }
+
+    /// Number of pieces that passed verification.
+    pub fn num_good(&self) -> usize {
+        self.num_good
+    }
+
+    /// Number of pieces that failed verification.
+    pub fn num_bad(&self) -> usize {
+        self.num_pieces - self.num_good
+    }
+
+    /// Whether or not the piece at the given index passed verification.
+    ///
+    /// Returns None if the index is out of range.
+    pub fn is_good(&self, index: usize) -> Option<bool> {
+        if index < self.num_pieces {
+            Some(self.bitfield[index / 8] & (0x80 >> (index % 8)) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Bitfield of good pieces, where the high bit of the first byte corresponds to piece zero.
+    ///
+    /// This is the same layout used by the bitfield message in the peer wire protocol.
+    pub fn bitfield(&self) -> &[u8] {
+        &self.bitfield
+    }
+}
+
+/// Stream through the content of each file, in order, verifying each piece as it is completed.
+///
+/// Files that fail to open, are shorter than expected, or have an unsafe path (see `Info::full_paths`)
+/// cause any pieces overlapping the missing data to fail verification. Padding files are never
+/// opened, since they are not written to disk, and are verified as if they were all zeros.
+pub fn verify_files<F, R>(info: &Info, mut open: F) -> VerifyReport
+    where F: FnMut(&Path) -> io::Result<R>, R: Read {
+    let piece_len = info.piece_length();
+    let mut report = VerifyReport::new(info.pieces().len());
+    if piece_len == 0 {
+        return report
+    }
+
+    let mut chunk = [0u8; READ_CHUNK_LEN];
+    let mut piece_hasher = ShaHashBuilder::new();
+    let mut piece_filled = 0u64;
+    let mut piece_index = 0;
+    let mut piece_complete = true;
+
+    for (file, full_path) in info.files().zip(info.full_paths()) {
+        let is_padding = file.is_padding();
+        let mut opt_reader = if is_padding { None } else { full_path.and_then(|path| open(&path).ok()) };
+        let mut remaining = file.length();
+
+        while remaining != 0 {
+            let read_len = cmp::min(cmp::min(piece_len - piece_filled, remaining), READ_CHUNK_LEN as u64) as usize;
+
+            let read_success = if is_padding {
+                io::repeat(0).read_exact(&mut chunk[..read_len]).is_ok()
+            } else {
+                opt_reader.as_mut().map_or(false, |reader| reader.read_exact(&mut chunk[..read_len]).is_ok())
+            };
+            if read_success {
+                piece_hasher = piece_hasher.add_bytes(&chunk[..read_len]);
+            } else {
+                // Any pieces overlapping the rest of this file are incomplete
+                opt_reader = None;
+                piece_complete = false;
+            }
+            remaining -= read_len as u64;
+            piece_filled += read_len as u64;
+
+            if piece_filled == piece_len {
+                verify_piece(info, &mut report, piece_index, &piece_hasher, piece_complete);
+
+                piece_hasher = ShaHashBuilder::new();
+                piece_filled = 0;
+                piece_index += 1;
+                piece_complete = true;
+            }
+        }
+    }
+
+    // Last piece may be shorter than the piece length
+    if piece_filled != 0 {
+        verify_piece(info, &mut report, piece_index, &piece_hasher, piece_complete);
+    }
+
+    report
+}
+
+fn verify_piece(info: &Info, report: &mut VerifyReport, index: usize, hasher: &ShaHashBuilder, complete: bool) {
+    let is_match = info.pieces().nth(index).map_or(false, |hash| hasher.build() == *hash);
+
+    if complete && is_match {
+        report.set_good(index);
+    }
+}
@@ -1,6 +1,6 @@
 extern crate bip_metainfo;
 
-use bip_metainfo::MetainfoBuilder;
+use bip_metainfo::{MetainfoBuilder, DirectAccessor, DEFAULT_CREATED_BY};
 
 const TRACKER: &'static str = "udp://foo.bar.baz:6969";
 const DATE: i64 = 1517651523851;
@@ -50,3 +50,55 @@ fn positive_set_created_by() {
 
     assert_eq!(builder.get_created_by(), Some(CREATED_BY.to_string()));
 }
+
+#[test]
+fn positive_default_created_by_and_creation_date() {
+    let builder = MetainfoBuilder::new();
+
+    assert_eq!(builder.get_created_by(), Some(DEFAULT_CREATED_BY.to_string()));
+    assert!(builder.get_creation_date().is_some());
+}
+
+#[test]
+fn positive_unset_created_by_and_creation_date() {
+    let builder = MetainfoBuilder::new()
+        .set_created_by(None)
+        .set_creation_date(None);
+
+    assert_eq!(builder.get_created_by(), None);
+    assert_eq!(builder.get_creation_date(), None);
+}
+
+#[test]
+fn positive_no_timestamp_unsets_fields() {
+    let builder = MetainfoBuilder::new()
+        .set_comment(Some(COMMENT))
+        .no_timestamp();
+
+    assert_eq!(builder.get_created_by(), None);
+    assert_eq!(builder.get_creation_date(), None);
+    assert_eq!(builder.get_comment(), Some(COMMENT.to_string()));
+}
+
+#[test]
+fn positive_reproducible_unsets_fields() {
+    let builder = MetainfoBuilder::new()
+        .set_comment(Some(COMMENT))
+        .reproducible();
+
+    assert_eq!(builder.get_created_by(), None);
+    assert_eq!(builder.get_creation_date(), None);
+    assert_eq!(builder.get_comment(), None);
+}
+
+#[test]
+fn positive_reproducible_identical_bytes() {
+    let file_data = b"Some file data";
+
+    let first = MetainfoBuilder::new().reproducible()
+        .build(1, DirectAccessor::new("FileName.txt", file_data), |_| ()).unwrap();
+    let second = MetainfoBuilder::new().reproducible()
+        .build(2, DirectAccessor::new("FileName.txt", file_data), |_| ()).unwrap();
+
+    assert_eq!(first, second);
+}
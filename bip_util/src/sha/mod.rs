@@ -1,5 +1,8 @@
 use std::ops::BitXor;
 
+use crypto::digest::Digest;
+use crypto::sha2::Sha256;
+
 use error::{LengthError, LengthResult, LengthErrorKind};
 
 mod builder;
@@ -21,6 +24,19 @@ impl ShaHash {
         ShaHashBuilder::new().add_bytes(bytes).build()
     }
 
+    /// Create a ShaHash by hashing the given bytes with SHA-256, truncated to SHA-1 length.
+    ///
+    /// This is the form in which v2 (BEP-52) info hashes are used in v1 contexts, such as the DHT.
+    pub fn from_bytes_truncated_sha256(bytes: &[u8]) -> ShaHash {
+        let mut buffer = [0u8; 32];
+
+        let mut sha = Sha256::new();
+        sha.input(bytes);
+        sha.result(&mut buffer);
+
+        ShaHash::from_hash(&buffer[..SHA_HASH_LEN]).unwrap()
+    }
+
     /// Create a ShaHash directly from the given hash.
     pub fn from_hash(hash: &[u8]) -> LengthResult<ShaHash> {
         if hash.len() != SHA_HASH_LEN {
@@ -241,4 +257,12 @@ mod tests {
 
         ShaHash::from_hash(&bits).unwrap();
     }
+
+    #[test]
+    fn positive_from_bytes_truncated_sha256() {
+        let expected = [0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41,
+                        0x40, 0xde, 0x5d, 0xae, 0x22, 0x23, 0xb0, 0x03, 0x61, 0xa3];
+
+        assert_eq!(ShaHash::from(expected), ShaHash::from_bytes_truncated_sha256(b"abc"));
+    }
 }